@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rustyaid::byte::{fill_random_bytes_with, some_byte_vector_with};
+use rustyaid::string::{
+    some_alphanumeric_string_of_length_with, some_string_from_with, some_string_of_length_with,
+    some_unicode_string_of_length_with, Charset,
+};
+
+const SIZES: [usize; 3] = [1024, 64 * 1024, 1024 * 1024];
+
+fn bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bytes");
+    let mut rng = StdRng::seed_from_u64(42);
+    for size in SIZES {
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("fill_random_bytes", size),
+            &size,
+            |b, &size| {
+                let mut buffer = vec![0; size];
+                b.iter(|| fill_random_bytes_with(&mut rng, black_box(&mut buffer)))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("some_byte_vector", size),
+            &size,
+            |b, &size| b.iter(|| some_byte_vector_with(&mut rng, black_box(size))),
+        );
+    }
+    group.finish();
+}
+
+fn strings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("strings");
+    let mut rng = StdRng::seed_from_u64(42);
+    let hex = Charset::from('0'..='9').union('a'..='f');
+    for size in SIZES {
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("some_string", size), &size, |b, &size| {
+            b.iter(|| some_string_of_length_with(&mut rng, black_box(size)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("some_alphanumeric_string", size),
+            &size,
+            |b, &size| {
+                b.iter(|| some_alphanumeric_string_of_length_with(&mut rng, black_box(size)))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("some_string_from", size),
+            &size,
+            |b, &size| b.iter(|| some_string_from_with(&mut rng, &hex, black_box(size..=size))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("some_unicode_string", size),
+            &size,
+            |b, &size| b.iter(|| some_unicode_string_of_length_with(&mut rng, black_box(size))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bytes, strings);
+criterion_main!(benches);
@@ -0,0 +1,122 @@
+use crate::field::field_value;
+use crate::some::add_trait_bounds;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Type};
+
+pub(crate) fn expand_derive_some_builder(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(named_fields_error()),
+        },
+        _ => return Err(named_fields_error()),
+    };
+    let vis = &input.vis;
+    let name = &input.ident;
+    let builder = format_ident!("{}SomeBuilder", name);
+    let builder_doc = format!(
+        "Builds a `{name}` from random values, except for the fields set on the builder\n\n\
+         Created with `{name}::some_builder()`."
+    );
+
+    if let Some(field) = fields.iter().find(|field| {
+        field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| GENERATED_METHODS.iter().any(|method| ident == method))
+    }) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "fields named `build` or `build_with` clash with the methods of the builder",
+        ));
+    }
+
+    let idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let setter_types: Vec<_> = types.iter().map(|ty| setter_type(ty)).collect();
+    let values = fields
+        .iter()
+        .map(field_value)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let bounded = add_trait_bounds(input.generics.clone());
+    let (bounded_impl_generics, _, bounded_where_clause) = bounded.split_for_impl();
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #impl_generics #where_clause {
+            #(#idents: ::core::option::Option<#types>,)*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Creates a builder generating random values for every field that is not set
+            #vis fn some_builder() -> #builder #ty_generics {
+                #builder {
+                    #(#idents: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #bounded_impl_generics #builder #ty_generics #bounded_where_clause {
+            #(
+                /// Sets the value of the field instead of generating a random one
+                #vis fn #idents(mut self, value: #setter_types) -> Self {
+                    self.#idents = ::core::option::Option::Some(value.into());
+                    self
+                }
+            )*
+
+            /// Builds the value, generating every field that is not set
+            ///
+            /// Draws the values from the seeded random number generator of the current thread.
+            #vis fn build(self) -> #name #ty_generics {
+                self.build_with(&mut ::rustyaid::rng::seeded_rng())
+            }
+
+            /// Builds the value, generating every field that is not set with the provided random
+            /// number generator
+            #vis fn build_with<__R: ::rustyaid::rand::Rng + ?::core::marker::Sized>(
+                self,
+                __rng: &mut __R,
+            ) -> #name #ty_generics {
+                #name {
+                    #(#idents: match self.#idents {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => #values,
+                    },)*
+                }
+            }
+        }
+    })
+}
+
+/// The methods generated on the builder, which no field can be named after
+const GENERATED_METHODS: [&str; 2] = ["build", "build_with"];
+
+/// The type of the value accepted by the setter of a field
+///
+/// Takes anything converting into a `String` or a `Vec`, such as `&str` or an array, and the type
+/// of the field itself otherwise so literals such as `42` infer the type of the field.
+fn setter_type(ty: &Type) -> TokenStream {
+    let converts = match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String" || segment.ident == "Vec"),
+        _ => false,
+    };
+    if converts {
+        quote!(impl ::core::convert::Into<#ty>)
+    } else {
+        quote!(#ty)
+    }
+}
+
+fn named_fields_error() -> syn::Error {
+    syn::Error::new(
+        Span::call_site(),
+        "SomeBuilder can only be derived for structs with named fields",
+    )
+}
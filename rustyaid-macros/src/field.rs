@@ -0,0 +1,121 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, ExprRange, Field, Path};
+
+/// How the value of a single field is generated, configured through `#[some(...)]`
+#[derive(Default)]
+struct FieldOptions {
+    range: Option<ExprRange>,
+    len: Option<ExprRange>,
+    charset: Option<&'static str>,
+    with: Option<Path>,
+    default: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut options = FieldOptions::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("some"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("range") {
+                    options.range = Some(range(meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("len") {
+                    options.len = Some(bounded_range(meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("with") {
+                    options.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    options.default = true;
+                } else if let Some(charset) = ["alpha", "numeric", "alphanumeric"]
+                    .into_iter()
+                    .find(|charset| meta.path.is_ident(charset))
+                {
+                    options.charset = Some(charset);
+                } else {
+                    return Err(meta.error(
+                        "expected `range`, `len`, `alpha`, `numeric`, `alphanumeric`, `with` \
+                         or `default`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        options.validate(field)?;
+        Ok(options)
+    }
+
+    fn validate(&self, field: &Field) -> syn::Result<()> {
+        let groups = [
+            self.range.is_some(),
+            self.len.is_some() || self.charset.is_some(),
+            self.with.is_some(),
+            self.default,
+        ];
+        if groups.into_iter().filter(|set| *set).count() > 1 {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`range`, `len` and the charsets, `with` and `default` cannot be combined",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn range(expr: Expr) -> syn::Result<ExprRange> {
+    match expr {
+        Expr::Range(range) => Ok(range),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected a range, such as `1..10`, `1..=10`, `1..` or `..10`",
+        )),
+    }
+}
+
+fn bounded_range(expr: Expr) -> syn::Result<ExprRange> {
+    match expr {
+        Expr::Range(range) if range.start.is_some() && range.end.is_some() => Ok(range),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected a range with a start and an end, such as `1..10` or `1..=10`",
+        )),
+    }
+}
+
+/// Generates the expression producing a random value for `field` from `__rng`
+pub(crate) fn field_value(field: &Field) -> syn::Result<TokenStream> {
+    let options = FieldOptions::parse(field)?;
+    let ty = &field.ty;
+    if options.default {
+        return Ok(quote!(::core::default::Default::default()));
+    }
+    if let Some(with) = options.with {
+        return Ok(quote!(#with(__rng)));
+    }
+    if let Some(range) = options.range {
+        return Ok(number_in_range(quote!(#ty), &range));
+    }
+    let charset = options
+        .charset
+        .map(|charset| format!("{charset}_"))
+        .unwrap_or_default();
+    if let Some(len) = options.len {
+        let generate = format_ident!("some_{}string_of_length_with", charset);
+        let length = number_in_range(quote!(usize), &len);
+        return Ok(quote!({
+            let __length = #length;
+            ::rustyaid::string::#generate(__rng, __length)
+        }));
+    }
+    if options.charset.is_some() {
+        let generate = format_ident!("some_{}string_with", charset);
+        return Ok(quote!(::rustyaid::string::#generate(__rng)));
+    }
+    Ok(quote!(<#ty as ::rustyaid::Some>::some_with(__rng)))
+}
+
+fn number_in_range(ty: TokenStream, range: &ExprRange) -> TokenStream {
+    quote!(::rustyaid::number::some_number_in_with::<#ty, _, _>(__rng, #range))
+}
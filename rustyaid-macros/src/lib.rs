@@ -0,0 +1,145 @@
+//! Procedural macros for the [rustyaid](https://crates.io/crates/rustyaid) crate.
+//!
+//! The macros are re-exported by rustyaid and should be used through it.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, DeriveInput, Expr, ExprLit, ItemFn, Lit, MetaNameValue, ReturnType, Token,
+};
+
+mod builder;
+mod field;
+mod some;
+
+/// Marks a function as a test whose values are drawn from a fresh seed
+///
+/// Expands to a `#[test]` function running the body through `rustyaid::rng::repeat_seeded()`.
+/// Accepts `iterations = N` to run the body `N` times, each with a different seed, or `seed = N` to
+/// run the body once with a fixed seed.
+#[proc_macro_attribute]
+pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args =
+        parse_macro_input!(attr with Punctuated::<MetaNameValue, Token![,]>::parse_terminated);
+    let function = parse_macro_input!(item as ItemFn);
+    expand_test(args, function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `rustyaid::Some` for a struct or enum
+///
+/// Every field is generated with its own `Some` implementation, for an enum a random variant is
+/// picked first.
+/// Fields accept `#[some(range = a..b)]`, `#[some(len = a..b)]`, `#[some(alpha)]`,
+/// `#[some(numeric)]`, `#[some(alphanumeric)]`, `#[some(with = function)]` and `#[some(default)]`
+/// to constrain their values, where the function takes the random number generator,
+/// `fn(&mut R) -> T`.
+#[proc_macro_derive(Some, attributes(some))]
+pub fn derive_some(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    some::expand_derive_some(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives a fixture builder for a struct with named fields
+///
+/// Generates a `<Name>SomeBuilder` type, created with `Name::some_builder()`, with a setter for
+/// every field. Fields that are not set are generated randomly when calling `build()`, accepting
+/// the same `#[some(...)]` attributes as `#[derive(Some)]`.
+#[proc_macro_derive(SomeBuilder, attributes(some))]
+pub fn derive_some_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    builder::expand_derive_some_builder(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_test(
+    args: Punctuated<MetaNameValue, Token![,]>,
+    function: ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut iterations = None;
+    let mut seed = None;
+    for arg in &args {
+        if arg.path.is_ident("iterations") {
+            iterations = Some(int_value::<usize>(&arg.value)?);
+        } else if arg.path.is_ident("seed") {
+            seed = Some(int_value::<u64>(&arg.value)?);
+        } else {
+            return Err(syn::Error::new_spanned(
+                &arg.path,
+                "expected `iterations` or `seed`",
+            ));
+        }
+    }
+    if iterations.is_some() && seed.is_some() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`iterations` and `seed` cannot be used together",
+        ));
+    }
+    if iterations == Some(0) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`iterations` must be greater than 0",
+        ));
+    }
+    if let ReturnType::Type(_, ty) = &function.sig.output {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "rustyaid::test functions cannot return a value",
+        ));
+    }
+    if !function.sig.inputs.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &function.sig.inputs,
+            "rustyaid::test functions cannot take arguments",
+        ));
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = function;
+    let body = match seed {
+        Some(seed) => quote! {
+            ::rustyaid::rng::set_seed(#seed);
+            #block
+        },
+        None => {
+            let iterations = iterations.unwrap_or(1);
+            quote! {
+                ::rustyaid::rng::repeat_seeded(#iterations, || #block);
+            }
+        }
+    };
+    Ok(quote! {
+        #[::core::prelude::v1::test]
+        #(#attrs)*
+        #vis #sig {
+            #body
+        }
+    })
+}
+
+fn int_value<T>(value: &Expr) -> syn::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "expected an integer literal",
+        )),
+    }
+}
@@ -0,0 +1,85 @@
+use crate::field::field_value;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Generics};
+
+pub(crate) fn expand_derive_some(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => fields_expr(quote!(Self), &data.fields)?,
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "Some cannot be derived for enums without variants",
+                ));
+            }
+            let count = data.variants.len();
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let ident = &variant.ident;
+                    let expr = fields_expr(quote!(Self::#ident), &variant.fields)?;
+                    Ok(quote!(#index => #expr,))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                match ::rustyaid::rand::Rng::gen_range(__rng, 0..#count) {
+                    #(#arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "Some cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = add_trait_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rustyaid::Some for #name #ty_generics #where_clause {
+            fn some_with<__R: ::rustyaid::rand::Rng + ?::core::marker::Sized>(__rng: &mut __R) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn fields_expr(path: TokenStream, fields: &Fields) -> syn::Result<TokenStream> {
+    Ok(match fields {
+        Fields::Named(fields) => {
+            let values = fields
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = &field.ident;
+                    let value = field_value(field)?;
+                    Ok(quote!(#ident: #value))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!(#path { #(#values),* })
+        }
+        Fields::Unnamed(fields) => {
+            let values = fields
+                .unnamed
+                .iter()
+                .map(field_value)
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!(#path(#(#values),*))
+        }
+        Fields::Unit => path,
+    })
+}
+
+pub(crate) fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::rustyaid::Some));
+    }
+    generics
+}
@@ -1,4 +1,7 @@
-use crate::number::some_number;
+use crate::number::some_number_with;
+use crate::rng::seeded_rng;
+use rand::Rng;
+use std::iter;
 
 /// Creates a new byte with a random value.
 ///
@@ -13,13 +16,71 @@ use crate::number::some_number;
 /// let b = some_byte();
 /// ```
 pub fn some_byte() -> u8 {
-    some_number()
+    some_byte_with(&mut seeded_rng())
+}
+
+/// Creates a new byte with a random value using `rng`
+///
+/// The bytes come from `rng` instead of the seeded random number generator of the current thread,
+/// otherwise see `some_byte()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::byte::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let b = some_byte_with(&mut rng);
+/// ```
+pub fn some_byte_with<R: Rng + ?Sized>(rng: &mut R) -> u8 {
+    some_number_with(rng)
+}
+
+/// Creates an infinite iterator of random bytes
+///
+/// Every item is generated like `some_byte()`, drawing from the seeded random number generator of
+/// the current thread, so many values can be generated with `take()`, `zip()` and the other
+/// iterator adapters instead of a manual loop.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::byte::*;
+/// let values: Vec<u8> = some_bytes().take(10).collect();
+/// assert_eq!(values.len(), 10);
+/// ```
+pub fn some_bytes() -> impl Iterator<Item = u8> {
+    let mut rng = seeded_rng();
+    iter::repeat_with(move || some_byte_with(&mut rng))
+}
+
+/// Creates an infinite iterator of random bytes using `rng`
+///
+/// The bytes come from `rng` instead of the seeded random number generator of the current thread,
+/// otherwise see `some_bytes()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::byte::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let values: Vec<u8> = some_bytes_with(&mut rng).take(10).collect();
+/// ```
+pub fn some_bytes_with<R: Rng + ?Sized>(rng: &mut R) -> impl Iterator<Item = u8> + '_ {
+    iter::repeat_with(move || some_byte_with(rng))
 }
 
 /// Creates a vec with random bytes of length `bound: usize`
 ///
 /// Creates a new vec of length `bound: usize` containing random bytes.
-/// Internally, this method calls the `byte::some_byte()` function to generate each random byte.
+/// Internally, this method calls the `byte::fill_random_bytes()` function to generate the bytes
+/// in blocks.
 ///
 /// # Examples
 ///
@@ -30,17 +91,74 @@ pub fn some_byte() -> u8 {
 /// let b_vec = some_byte_vector(128);
 /// ```
 pub fn some_byte_vector(bound: usize) -> Vec<u8> {
-    let mut vec = Vec::new();
-    for _ in 0..bound {
-        vec.push(some_number())
-    }
+    some_byte_vector_with(&mut seeded_rng(), bound)
+}
+
+/// Creates a vec with random bytes of length `bound: usize` using `rng`
+///
+/// The bytes come from `rng` instead of the seeded random number generator of the current thread,
+/// otherwise see `some_byte_vector()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::byte::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// // Create vector of 128 bytes
+/// let b_vec = some_byte_vector_with(&mut rng, 128);
+/// ```
+pub fn some_byte_vector_with<R: Rng + ?Sized>(rng: &mut R, bound: usize) -> Vec<u8> {
+    let mut vec = vec![0; bound];
+    fill_random_bytes_with(rng, &mut vec);
     vec
 }
 
+/// Fills a slice with random bytes
+///
+/// Overwrites every byte of `bytes` with a random value, generating the bytes in blocks rather
+/// than one at a time, which makes it the fastest way to generate large amounts of data.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::byte::*;
+/// let mut buffer = [0u8; 4096];
+/// fill_random_bytes(&mut buffer);
+/// ```
+pub fn fill_random_bytes(bytes: &mut [u8]) {
+    fill_random_bytes_with(&mut seeded_rng(), bytes)
+}
+
+/// Fills a slice with random bytes using `rng`
+///
+/// The bytes come from `rng` instead of the seeded random number generator of the current thread,
+/// otherwise see `fill_random_bytes()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::byte::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let mut buffer = [0u8; 4096];
+/// fill_random_bytes_with(&mut rng, &mut buffer);
+/// ```
+pub fn fill_random_bytes_with<R: Rng + ?Sized>(rng: &mut R, bytes: &mut [u8]) {
+    rng.fill_bytes(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::number::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn can_create_random_byte() {
@@ -53,4 +171,26 @@ mod tests {
         let byte_vector = some_byte_vector(bound);
         assert_eq!(byte_vector.len(), bound);
     }
+
+    #[test]
+    fn can_create_same_byte_vector_from_same_rng() {
+        let seed = some_number();
+        let first = some_byte_vector_with(&mut StdRng::seed_from_u64(seed), 64);
+        let second = some_byte_vector_with(&mut StdRng::seed_from_u64(seed), 64);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn can_create_iterator_of_bytes() {
+        let actual: Vec<(u8, u8)> = some_bytes().zip(some_bytes()).take(64).collect();
+        assert_eq!(actual.len(), 64);
+    }
+
+    #[test]
+    fn can_fill_random_bytes() {
+        let mut actual = [0u8; 1024];
+        fill_random_bytes(&mut actual);
+        assert!(actual.iter().any(|byte| *byte != 0));
+        fill_random_bytes(&mut []);
+    }
 }
@@ -0,0 +1,724 @@
+use crate::number::try_some_length_in_with;
+use crate::rng::seeded_rng;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::WeightedIndex;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use std::collections::{btree_map, hash_map};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::{AddAssign, RangeBounds};
+
+const DEFAULT_MAX_LENGTH: usize = 32;
+const MAX_ATTEMPTS_PER_ELEMENT: usize = 100;
+
+/// Picks a random element of a slice
+///
+/// Returns a reference to one of `elements`, each element being equally likely.
+///
+/// # Panics
+///
+/// Panics when `elements` is empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// let colour = some_element_of(&["red", "green", "blue"]);
+/// ```
+pub fn some_element_of<T>(elements: &[T]) -> &T {
+    some_element_of_with(&mut seeded_rng(), elements)
+}
+
+/// Picks a random element of a slice using the provided random number generator
+///
+/// Works like `some_element_of()`, but draws the element from `rng` instead of the seeded random
+/// number generator of the current thread.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let colour = some_element_of_with(&mut rng, &["red", "green", "blue"]);
+/// ```
+pub fn some_element_of_with<'a, T, R: Rng + ?Sized>(rng: &mut R, elements: &'a [T]) -> &'a T {
+    elements
+        .choose(rng)
+        .unwrap_or_else(|| panic!("Cannot pick an element of an empty slice"))
+}
+
+/// Picks a random element of a slice of weighted elements
+///
+/// Returns a reference to one of the elements of `elements`, each element being picked with a
+/// probability proportional to its weight.
+/// Weights can be integers or floats, elements with a weight of `0` are never picked.
+///
+/// # Panics
+///
+/// Panics when `elements` is empty, a weight is negative or all weights are `0`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// // Picks "GET" 8 times out of 10
+/// let method = some_weighted_element_of(&[("GET", 8), ("POST", 1), ("DELETE", 1)]);
+/// ```
+pub fn some_weighted_element_of<T, W>(elements: &[(T, W)]) -> &T
+where
+    W: SampleUniform + PartialOrd + Default + Clone + for<'w> AddAssign<&'w W>,
+{
+    some_weighted_element_of_with(&mut seeded_rng(), elements)
+}
+
+/// Picks a random element of a slice of weighted elements using the provided random number
+/// generator
+///
+/// Works like `some_weighted_element_of()`, but draws the element from `rng` instead of the seeded
+/// random number generator of the current thread.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let method = some_weighted_element_of_with(&mut rng, &[("GET", 0.8), ("POST", 0.2)]);
+/// ```
+pub fn some_weighted_element_of_with<'a, T, W, R>(rng: &mut R, elements: &'a [(T, W)]) -> &'a T
+where
+    W: SampleUniform + PartialOrd + Default + Clone + for<'w> AddAssign<&'w W>,
+    R: Rng + ?Sized,
+{
+    let weights = WeightedIndex::new(elements.iter().map(|(_, weight)| weight))
+        .unwrap_or_else(|error| panic!("Cannot pick a weighted element: {error}"));
+    &elements[rng.sample(weights)].0
+}
+
+/// Picks a random subset of the elements of a slice
+///
+/// Returns a vec holding each of `elements` with a probability of one half, keeping their order.
+/// The subset can be empty or hold all elements.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// let toppings = some_subset_of(&["cheese", "ham", "pineapple"]);
+/// assert!(toppings.len() <= 3);
+/// ```
+pub fn some_subset_of<T: Clone>(elements: &[T]) -> Vec<T> {
+    some_subset_of_with(&mut seeded_rng(), elements)
+}
+
+/// Picks a random subset of the elements of a slice using the provided random number generator
+///
+/// Works like `some_subset_of()`, but draws the elements from `rng` instead of the seeded random
+/// number generator of the current thread.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let toppings = some_subset_of_with(&mut rng, &["cheese", "ham", "pineapple"]);
+/// ```
+pub fn some_subset_of_with<T: Clone, R: Rng + ?Sized>(rng: &mut R, elements: &[T]) -> Vec<T> {
+    elements.iter().filter(|_| rng.gen()).cloned().collect()
+}
+
+/// Shuffles the elements of a slice
+///
+/// Returns a vec holding all of `elements` in a random order.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// let order = some_permutation_of(&[1, 2, 3, 4]);
+/// assert_eq!(order.len(), 4);
+/// ```
+pub fn some_permutation_of<T: Clone>(elements: &[T]) -> Vec<T> {
+    some_permutation_of_with(&mut seeded_rng(), elements)
+}
+
+/// Shuffles the elements of a slice using the provided random number generator
+///
+/// Works like `some_permutation_of()`, but draws the order from `rng` instead of the seeded random
+/// number generator of the current thread.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let order = some_permutation_of_with(&mut rng, &[1, 2, 3, 4]);
+/// ```
+pub fn some_permutation_of_with<T: Clone, R: Rng + ?Sized>(rng: &mut R, elements: &[T]) -> Vec<T> {
+    let mut permutation = elements.to_vec();
+    permutation.shuffle(rng);
+    permutation
+}
+
+/// Picks `amount` random elements of a slice
+///
+/// Returns a vec holding `amount` of `elements`, never picking the same position twice.
+/// The order of the picked elements is random.
+///
+/// # Panics
+///
+/// Panics when `amount` is greater than the number of elements.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// let winners = some_sample_of(&["ann", "bob", "cat", "dan"], 2);
+/// assert_eq!(winners.len(), 2);
+/// ```
+pub fn some_sample_of<T: Clone>(elements: &[T], amount: usize) -> Vec<T> {
+    some_sample_of_with(&mut seeded_rng(), elements, amount)
+}
+
+/// Picks `amount` random elements of a slice using the provided random number generator
+///
+/// Works like `some_sample_of()`, but draws the elements from `rng` instead of the seeded random
+/// number generator of the current thread.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let winners = some_sample_of_with(&mut rng, &["ann", "bob", "cat", "dan"], 2);
+/// ```
+pub fn some_sample_of_with<T: Clone, R: Rng + ?Sized>(
+    rng: &mut R,
+    elements: &[T],
+    amount: usize,
+) -> Vec<T> {
+    if amount > elements.len() {
+        panic!(
+            "Cannot pick {amount} elements of a slice of {} elements",
+            elements.len()
+        )
+    }
+    let mut sample: Vec<T> = index::sample(rng, elements.len(), amount)
+        .into_iter()
+        .map(|index| elements[index].clone())
+        .collect();
+    sample.shuffle(rng);
+    sample
+}
+
+/// Creates a new vec of random elements
+///
+/// Creates a new vec with a length within `length`, an unbounded end standing for a length of up
+/// to 32, and fills it with elements returned by `generate`.
+/// Any function without arguments, such as `some_string` or `some_number::<u8>`, can be passed as
+/// `generate`.
+///
+/// # Panics
+///
+/// Panics when `length` is empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// use rustyaid::string::some_alpha_string;
+/// let names = some_vec_of(1..10, some_alpha_string);
+/// let ids = some_vec_of(5..=5, || rustyaid::number::some_number_in(1..1000));
+/// assert_eq!(ids.len(), 5);
+/// ```
+pub fn some_vec_of<T>(length: impl RangeBounds<usize>, mut generate: impl FnMut() -> T) -> Vec<T> {
+    some_vec_of_with(&mut seeded_rng(), length, |_| generate())
+}
+
+/// Creates a new vec of random elements using the provided random number generator
+///
+/// Works like `some_vec_of()`, but draws the length from `rng` instead of the seeded random number
+/// generator of the current thread and passes `rng` to `generate`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::string::some_alpha_string_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let names = some_vec_of_with(&mut rng, 1..10, |rng| some_alpha_string_with(rng));
+/// ```
+pub fn some_vec_of_with<T, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut(&mut R) -> T,
+) -> Vec<T> {
+    let length = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a collection with a length in an empty range"));
+    (0..length).map(|_| generate(rng)).collect()
+}
+
+/// Creates a new vec deque of random elements
+///
+/// Works like `some_vec_of()`, but creates a `VecDeque`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number;
+/// let queue = some_vec_deque_of(1..10, some_number::<u32>);
+/// ```
+pub fn some_vec_deque_of<T>(
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut() -> T,
+) -> VecDeque<T> {
+    some_vec_deque_of_with(&mut seeded_rng(), length, |_| generate())
+}
+
+/// Creates a new vec deque of random elements using the provided random number generator
+///
+/// Works like `some_vec_deque_of()`, but draws the length from `rng` instead of the seeded random
+/// number generator of the current thread and passes `rng` to `generate`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let queue = some_vec_deque_of_with(&mut rng, 1..10, |rng| some_number_with::<u32, _>(rng));
+/// ```
+pub fn some_vec_deque_of_with<T, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    generate: impl FnMut(&mut R) -> T,
+) -> VecDeque<T> {
+    some_vec_of_with(rng, length, generate).into()
+}
+
+/// Creates a new hash set of unique random elements
+///
+/// Creates a new set with a length within `length`, an unbounded end standing for a length of up
+/// to 32, and fills it with elements returned by `generate`.
+/// Elements equal to one already in the set are generated again, so the set always has the chosen
+/// length.
+///
+/// # Panics
+///
+/// Panics when `length` is empty, or when `generate` keeps returning elements already in the set,
+/// such as when asking for more elements than `generate` can return.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// let ports = some_hash_set_of(10..=10, || rustyaid::number::some_number_in(8000..8020));
+/// assert_eq!(ports.len(), 10);
+/// ```
+pub fn some_hash_set_of<T: Eq + Hash>(
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut() -> T,
+) -> HashSet<T> {
+    some_hash_set_of_with(&mut seeded_rng(), length, |_| generate())
+}
+
+/// Creates a new hash set of unique random elements using the provided random number generator
+///
+/// Works like `some_hash_set_of()`, but draws the length from `rng` instead of the seeded random
+/// number generator of the current thread and passes `rng` to `generate`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::string::some_alpha_string_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let names = some_hash_set_of_with(&mut rng, 1..10, |rng| some_alpha_string_with(rng));
+/// ```
+pub fn some_hash_set_of_with<T: Eq + Hash, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut(&mut R) -> T,
+) -> HashSet<T> {
+    let length = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a collection with a length in an empty range"));
+    let mut set = HashSet::with_capacity(length);
+    fill_unique(rng, length, |rng| set.insert(generate(rng)));
+    set
+}
+
+/// Creates a new B-tree set of unique random elements
+///
+/// Works like `some_hash_set_of()`, but creates a `BTreeSet`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number;
+/// let sorted = some_btree_set_of(1..10, some_number::<i64>);
+/// ```
+pub fn some_btree_set_of<T: Ord>(
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut() -> T,
+) -> BTreeSet<T> {
+    some_btree_set_of_with(&mut seeded_rng(), length, |_| generate())
+}
+
+/// Creates a new B-tree set of unique random elements using the provided random number generator
+///
+/// Works like `some_btree_set_of()`, but draws the length from `rng` instead of the seeded random
+/// number generator of the current thread and passes `rng` to `generate`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let sorted = some_btree_set_of_with(&mut rng, 1..10, |rng| some_number_with::<i64, _>(rng));
+/// ```
+pub fn some_btree_set_of_with<T: Ord, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    mut generate: impl FnMut(&mut R) -> T,
+) -> BTreeSet<T> {
+    let length = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a collection with a length in an empty range"));
+    let mut set = BTreeSet::new();
+    fill_unique(rng, length, |rng| set.insert(generate(rng)));
+    set
+}
+
+/// Creates a new hash map with unique random keys
+///
+/// Creates a new map with a length within `length`, an unbounded end standing for a length of up
+/// to 32, with keys returned by `generate_key` and values returned by `generate_value`.
+/// Keys equal to one already in the map are generated again, so the map always has the chosen
+/// length.
+///
+/// # Panics
+///
+/// Panics when `length` is empty, or when `generate_key` keeps returning keys already in the map,
+/// such as when asking for more keys than `generate_key` can return.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number;
+/// use rustyaid::string::some_alpha_string;
+/// let ages = some_hash_map_of(1..10, some_alpha_string, some_number::<u8>);
+/// ```
+pub fn some_hash_map_of<K: Eq + Hash, V>(
+    length: impl RangeBounds<usize>,
+    mut generate_key: impl FnMut() -> K,
+    mut generate_value: impl FnMut() -> V,
+) -> HashMap<K, V> {
+    some_hash_map_of_with(
+        &mut seeded_rng(),
+        length,
+        |_| generate_key(),
+        |_| generate_value(),
+    )
+}
+
+/// Creates a new hash map with unique random keys using the provided random number generator
+///
+/// Works like `some_hash_map_of()`, but draws the length from `rng` instead of the seeded random
+/// number generator of the current thread and passes `rng` to `generate_key` and `generate_value`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number_with;
+/// use rustyaid::string::some_alpha_string_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let ages = some_hash_map_of_with(
+///     &mut rng,
+///     1..10,
+///     |rng| some_alpha_string_with(rng),
+///     |rng| some_number_with::<u8, _>(rng),
+/// );
+/// ```
+pub fn some_hash_map_of_with<K: Eq + Hash, V, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    mut generate_key: impl FnMut(&mut R) -> K,
+    mut generate_value: impl FnMut(&mut R) -> V,
+) -> HashMap<K, V> {
+    let length = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a collection with a length in an empty range"));
+    let mut map = HashMap::with_capacity(length);
+    fill_unique(rng, length, |rng| match map.entry(generate_key(rng)) {
+        hash_map::Entry::Occupied(_) => false,
+        hash_map::Entry::Vacant(entry) => {
+            entry.insert(generate_value(rng));
+            true
+        }
+    });
+    map
+}
+
+/// Creates a new B-tree map with unique random keys
+///
+/// Works like `some_hash_map_of()`, but creates a `BTreeMap`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number;
+/// use rustyaid::string::some_alpha_string;
+/// let ages = some_btree_map_of(1..10, some_alpha_string, some_number::<u8>);
+/// ```
+pub fn some_btree_map_of<K: Ord, V>(
+    length: impl RangeBounds<usize>,
+    mut generate_key: impl FnMut() -> K,
+    mut generate_value: impl FnMut() -> V,
+) -> BTreeMap<K, V> {
+    some_btree_map_of_with(
+        &mut seeded_rng(),
+        length,
+        |_| generate_key(),
+        |_| generate_value(),
+    )
+}
+
+/// Creates a new B-tree map with unique random keys using the provided random number generator
+///
+/// Works like `some_btree_map_of()`, but draws the length from `rng` instead of the seeded random
+/// number generator of the current thread and passes `rng` to `generate_key` and `generate_value`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::collection::*;
+/// use rustyaid::number::some_number_with;
+/// use rustyaid::string::some_alpha_string_with;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let ages = some_btree_map_of_with(
+///     &mut rng,
+///     1..10,
+///     |rng| some_alpha_string_with(rng),
+///     |rng| some_number_with::<u8, _>(rng),
+/// );
+/// ```
+pub fn some_btree_map_of_with<K: Ord, V, R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    mut generate_key: impl FnMut(&mut R) -> K,
+    mut generate_value: impl FnMut(&mut R) -> V,
+) -> BTreeMap<K, V> {
+    let length = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a collection with a length in an empty range"));
+    let mut map = BTreeMap::new();
+    fill_unique(rng, length, |rng| match map.entry(generate_key(rng)) {
+        btree_map::Entry::Occupied(_) => false,
+        btree_map::Entry::Vacant(entry) => {
+            entry.insert(generate_value(rng));
+            true
+        }
+    });
+    map
+}
+
+/// Calls `insert` until it inserted `length` unique elements
+///
+/// Gives up after `MAX_ATTEMPTS_PER_ELEMENT` attempts for every element, rather than looping
+/// forever on a generator that cannot return enough unique elements.
+fn fill_unique<R: Rng + ?Sized>(
+    rng: &mut R,
+    length: usize,
+    mut insert: impl FnMut(&mut R) -> bool,
+) {
+    let mut inserted = 0;
+    for _ in 0..length.saturating_mul(MAX_ATTEMPTS_PER_ELEMENT) {
+        if inserted == length {
+            return;
+        }
+        if insert(rng) {
+            inserted += 1;
+        }
+    }
+    if inserted < length {
+        panic!(
+            "Cannot generate {length} unique elements, only {inserted} were found as the generator \
+             keeps repeating them"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Some;
+
+    #[test]
+    fn can_pick_every_element_of() {
+        let elements = [1, 2, 3];
+        let picked: Vec<i32> = (0..100).map(|_| *some_element_of(&elements)).collect();
+        for element in elements {
+            assert!(picked.contains(&element));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "empty slice")]
+    fn cannot_pick_element_of_empty_slice() {
+        some_element_of::<u8>(&[]);
+    }
+
+    #[test]
+    fn can_pick_weighted_element_of() {
+        let elements = [("often", 98), ("never", 0), ("rarely", 2)];
+        let picked: Vec<&str> = (0..1000)
+            .map(|_| *some_weighted_element_of(&elements))
+            .collect();
+        let often = picked.iter().filter(|element| **element == "often").count();
+        assert!(often > 900);
+        assert!(!picked.contains(&"never"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot pick a weighted element")]
+    fn cannot_pick_weighted_element_of_zero_weights() {
+        some_weighted_element_of(&[("a", 0.0), ("b", 0.0)]);
+    }
+
+    #[test]
+    fn can_pick_subset_of() {
+        let elements = [1, 2, 3, 4, 5, 6, 7, 8];
+        let subset = some_subset_of(&elements);
+        assert!(subset.windows(2).all(|pair| pair[0] < pair[1]));
+        let sizes: Vec<usize> = (0..100).map(|_| some_subset_of(&elements).len()).collect();
+        assert!(sizes.iter().any(|size| *size != sizes[0]));
+    }
+
+    #[test]
+    fn can_create_permutation_of() {
+        let elements: Vec<u32> = (0..100).collect();
+        let mut permutation = some_permutation_of(&elements);
+        assert_ne!(permutation, elements);
+        permutation.sort();
+        assert_eq!(permutation, elements);
+    }
+
+    #[test]
+    fn can_pick_sample_of() {
+        let elements: Vec<u32> = (0..10).collect();
+        let mut sample = some_sample_of(&elements, 5);
+        assert_eq!(sample.len(), 5);
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 5);
+        assert_eq!(some_sample_of(&elements, 10).len(), 10);
+        assert!(some_sample_of(&elements, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot pick 4 elements")]
+    fn cannot_pick_sample_larger_than_slice() {
+        some_sample_of(&[1, 2, 3], 4);
+    }
+
+    #[test]
+    fn can_create_vec_of() {
+        let actual = some_vec_of(3..=5, || 7);
+        assert!((3..=5).contains(&actual.len()));
+        assert!(actual.iter().all(|element| *element == 7));
+        assert!(some_vec_of(0..1, || 7).is_empty());
+        assert!(some_vec_of(.., || 7).len() <= DEFAULT_MAX_LENGTH);
+        assert_eq!(some_vec_of(40.., || 7).len(), 40);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn cannot_create_vec_of_length_in_empty_range() {
+        some_vec_of(5..5, || 7);
+    }
+
+    #[test]
+    fn can_create_vec_deque_of() {
+        let actual = some_vec_deque_of(4..=4, crate::number::some_number::<u8>);
+        assert_eq!(actual.len(), 4);
+    }
+
+    #[test]
+    fn can_create_sets_of_unique_elements() {
+        let actual = some_hash_set_of(10..=10, || crate::number::some_number_in(0..10));
+        assert_eq!(actual.len(), 10);
+        let actual = some_btree_set_of(10..=10, || crate::number::some_number_in(0..10));
+        assert_eq!(
+            actual.into_iter().collect::<Vec<u8>>(),
+            (0..10).collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn can_create_maps_with_unique_keys() {
+        let actual = some_hash_map_of(
+            4..=4,
+            || crate::number::some_number_in(0..4),
+            crate::string::some_string,
+        );
+        assert_eq!(actual.len(), 4);
+        let actual = some_btree_map_of(4..=4, || crate::number::some_number_in(0..4), || true);
+        assert_eq!(
+            actual.keys().copied().collect::<Vec<u8>>(),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot generate 3 unique elements")]
+    fn cannot_create_set_of_more_elements_than_generator_returns() {
+        some_hash_set_of(3..=3, bool::some);
+    }
+}
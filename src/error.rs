@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// The ways a generator can be misused
+///
+/// Returned by the `try_` generators, the other generators panic with the same message instead.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// use rustyaid::Error;
+/// assert_eq!(try_some_number_between(20, 10), Err(Error::EmptyRange));
+/// assert_eq!(try_some_number_greater_than(i32::MAX), Err(Error::Overflow));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no value within the requested bounds, such as between `20` and `10`
+    EmptyRange,
+    /// The generator does not handle the requested bound, such as a negative lower bound or an
+    /// infinite bound
+    UnsupportedBound,
+    /// The requested bound is at the limit of the type, so no greater value can be generated
+    Overflow,
+    /// A unique generator keeps returning values it already generated, such as when asking for
+    /// more unique values than the generator can return
+    Exhausted,
+}
+
+/// The result of the `try_` generators
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyRange => write!(f, "Cannot generate a value from an empty range"),
+            Error::UnsupportedBound => {
+                write!(f, "Cannot generate a value for an unsupported bound")
+            }
+            Error::Overflow => write!(f, "Cannot generate a value beyond the maximum of the type"),
+            Error::Exhausted => write!(
+                f,
+                "Cannot generate a unique value, the generator keeps repeating generated values"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_display_error() {
+        assert_eq!(
+            Error::EmptyRange.to_string(),
+            "Cannot generate a value from an empty range"
+        );
+    }
+}
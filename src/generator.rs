@@ -0,0 +1,393 @@
+use crate::number::some_number_in_with;
+use crate::rng::seeded_rng;
+use crate::string::{some_string_from_with, some_string_with, Charset};
+use crate::Some;
+use num::{Bounded, FromPrimitive, Num, ToPrimitive};
+use rand::distributions::uniform::SampleUniform;
+use rand::{Rng, RngCore};
+use std::iter;
+use std::ops::{Bound, RangeBounds};
+use std::rc::Rc;
+
+/// The number of values `Generator::filter()` draws before giving up
+const MAX_FILTER_ATTEMPTS: usize = 1000;
+
+/// A reusable, composable source of random values
+///
+/// Wraps a `some_*` function so it can be passed around, configured once and combined with other
+/// generators through `map()`, `filter()`, `zip()` and `flat_map()`.
+/// Generators are cheap to clone, clones share the same underlying function.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+///
+/// let even = numbers(1..10).map(|n: u32| n * 2);
+/// let names = strings().filter(|s| !s.trim().is_empty());
+/// let users = zip(numbers(1..1000), names);
+/// let (id, name): (u32, String) = users.generate();
+/// let many_users: Vec<(u32, String)> = users.iter().take(10).collect();
+/// assert!(even.generate() % 2 == 0);
+/// ```
+pub struct Generator<T> {
+    generate: Rc<GenerateFn<T>>,
+}
+
+type GenerateFn<T> = dyn Fn(&mut dyn RngCore) -> T;
+
+impl<T> Clone for Generator<T> {
+    fn clone(&self) -> Self {
+        Generator {
+            generate: Rc::clone(&self.generate),
+        }
+    }
+}
+
+impl<T: 'static> Generator<T> {
+    /// Creates a generator calling `generate` with a random number generator for every value
+    pub fn from_fn(generate: impl Fn(&mut dyn RngCore) -> T + 'static) -> Self {
+        Generator {
+            generate: Rc::new(generate),
+        }
+    }
+
+    /// Generates a new value
+    ///
+    /// Draws the value from the seeded random number generator of the current thread.
+    pub fn generate(&self) -> T {
+        self.generate_with(&mut seeded_rng())
+    }
+
+    /// Generates a new value using the provided random number generator
+    pub fn generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        (self.generate)(&mut DynRng(rng))
+    }
+
+    /// Creates an infinite iterator of values of this generator
+    ///
+    /// Draws the values from the seeded random number generator of the current thread.
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        let generator = self.clone();
+        let mut rng = seeded_rng();
+        iter::repeat_with(move || generator.generate_with(&mut rng))
+    }
+
+    /// Creates an infinite iterator of values of this generator using the provided random number
+    /// generator
+    pub fn iter_with<'a, R: Rng + ?Sized>(
+        &'a self,
+        rng: &'a mut R,
+    ) -> impl Iterator<Item = T> + 'a {
+        iter::repeat_with(move || self.generate_with(rng))
+    }
+
+    /// Creates a generator transforming every value of this generator with `f`
+    pub fn map<U: 'static>(self, f: impl Fn(T) -> U + 'static) -> Generator<U> {
+        Generator::from_fn(move |rng| f((self.generate)(rng)))
+    }
+
+    /// Creates a generator only returning the values of this generator matching `predicate`
+    ///
+    /// Values not matching `predicate` are generated again.
+    ///
+    /// # Panics
+    ///
+    /// The created generator panics when 1000 values in a row do not match `predicate`, rather
+    /// than looping forever on a predicate that rejects most values.
+    pub fn filter(self, predicate: impl Fn(&T) -> bool + 'static) -> Generator<T> {
+        Generator::from_fn(move |rng| {
+            for _ in 0..MAX_FILTER_ATTEMPTS {
+                let value = (self.generate)(rng);
+                if predicate(&value) {
+                    return value;
+                }
+            }
+            panic!(
+                "Cannot generate a value matching the filter after {MAX_FILTER_ATTEMPTS} attempts"
+            )
+        })
+    }
+
+    /// Creates a generator pairing the values of this generator with the values of `other`
+    pub fn zip<U: 'static>(self, other: Generator<U>) -> Generator<(T, U)> {
+        Generator::from_fn(move |rng| ((self.generate)(rng), (other.generate)(rng)))
+    }
+
+    /// Creates a generator drawing every value from the generator `f` returns for a value of this
+    /// generator
+    ///
+    /// Lets a generator depend on a random value, such as strings of a random length.
+    pub fn flat_map<U: 'static>(self, f: impl Fn(T) -> Generator<U> + 'static) -> Generator<U> {
+        Generator::from_fn(move |rng| f((self.generate)(rng)).generate_with(rng))
+    }
+}
+
+/// Creates a generator pairing the values of `first` with the values of `second`
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let points = zip(numbers(-10..10), numbers(-10..10));
+/// let (x, y): (i32, i32) = points.generate();
+/// ```
+pub fn zip<T: 'static, U: 'static>(first: Generator<T>, second: Generator<U>) -> Generator<(T, U)> {
+    first.zip(second)
+}
+
+/// Creates a generator of numbers within a range
+///
+/// Generates the numbers with `number::some_number_in()`, accepting the same ranges.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let dice = numbers(1..=6);
+/// let n: u8 = dice.generate();
+/// ```
+pub fn numbers<TYPE, RANGE>(range: RANGE) -> Generator<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform + 'static,
+    RANGE: RangeBounds<TYPE>,
+{
+    let bounds: (Bound<TYPE>, Bound<TYPE>) =
+        (range.start_bound().cloned(), range.end_bound().cloned());
+    Generator::from_fn(move |rng| some_number_in_with(rng, bounds))
+}
+
+/// Creates a generator of strings
+///
+/// Generates the strings with `string::some_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let s = strings().generate();
+/// ```
+pub fn strings() -> Generator<String> {
+    Generator::from_fn(|rng| some_string_with(rng))
+}
+
+/// Creates a generator of strings of characters from a charset with a length within a range
+///
+/// Generates the strings with `string::some_string_from()`, accepting the same ranges.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// use rustyaid::string::Charset;
+/// let hex = strings_from(Charset::from('0'..='9').union('a'..='f'), 8..=8);
+/// assert_eq!(hex.generate().len(), 8);
+/// ```
+pub fn strings_from(charset: Charset, length: impl RangeBounds<usize>) -> Generator<String> {
+    let bounds = (length.start_bound().cloned(), length.end_bound().cloned());
+    Generator::from_fn(move |rng| some_string_from_with(rng, &charset, bounds))
+}
+
+/// Creates a generator of bytes
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let b: u8 = bytes().generate();
+/// ```
+pub fn bytes() -> Generator<u8> {
+    Generator::from_fn(|rng| rng.gen())
+}
+
+/// Creates a generator of any type implementing the `Some` trait
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let pairs = some::<(bool, char)>();
+/// let (flag, c) = pairs.generate();
+/// ```
+pub fn some<T: Some + 'static>() -> Generator<T> {
+    Generator::from_fn(|rng| T::some_with(rng))
+}
+
+/// Creates a generator always returning `value`
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// assert_eq!(just(42).generate(), 42);
+/// ```
+pub fn just<T: Clone + 'static>(value: T) -> Generator<T> {
+    Generator::from_fn(move |_| value.clone())
+}
+
+/// Creates a generator returning one of `elements`
+///
+/// # Panics
+///
+/// Panics when `elements` is empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let colours = elements_of(vec!["red", "green", "blue"]);
+/// let colour = colours.generate();
+/// ```
+pub fn elements_of<T: Clone + 'static>(elements: Vec<T>) -> Generator<T> {
+    if elements.is_empty() {
+        panic!("Cannot create a generator of an empty list of elements")
+    }
+    Generator::from_fn(move |rng| elements[rng.gen_range(0..elements.len())].clone())
+}
+
+/// Creates a generator drawing every value from one of `generators`
+///
+/// # Panics
+///
+/// Panics when `generators` is empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// let edgy = one_of(vec![just(0), just(i64::MAX), numbers(..)]);
+/// let n = edgy.generate();
+/// ```
+pub fn one_of<T: 'static>(generators: Vec<Generator<T>>) -> Generator<T> {
+    if generators.is_empty() {
+        panic!("Cannot create a generator of an empty list of generators")
+    }
+    Generator::from_fn(move |rng| {
+        let index = rng.gen_range(0..generators.len());
+        (generators[index].generate)(rng)
+    })
+}
+
+/// Lets generators accept any `Rng`, including unsized ones, as a `dyn RngCore`
+struct DynRng<'a, R: ?Sized>(&'a mut R);
+
+impl<R: RngCore + ?Sized> RngCore for DynRng<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn can_generate_numbers_in_range() {
+        let generator = numbers(10..20);
+        for _ in 0..100 {
+            assert!((10..20).contains(&generator.generate()));
+        }
+    }
+
+    #[test]
+    fn can_map_generator() {
+        let generator = numbers(1..10).map(|n: u32| n * 2);
+        for _ in 0..100 {
+            let actual = generator.generate();
+            assert!(actual.is_multiple_of(2) && (2..20).contains(&actual));
+        }
+    }
+
+    #[test]
+    fn can_filter_generator() {
+        let generator = numbers(0..100).filter(|n: &u8| n.is_multiple_of(10));
+        for _ in 0..100 {
+            assert_eq!(generator.generate() % 10, 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot generate a value matching the filter")]
+    fn cannot_filter_generator_rejecting_every_value() {
+        strings().filter(|s| s.is_empty()).generate();
+    }
+
+    #[test]
+    fn can_zip_generators() {
+        let generator = zip(just(1), strings_from(Charset::numeric(), 3..=3));
+        let (number, string) = generator.generate();
+        assert_eq!(number, 1);
+        assert_eq!(string.len(), 3);
+    }
+
+    #[test]
+    fn can_flat_map_generator() {
+        let generator = numbers(1..=5).flat_map(|length: usize| {
+            strings_from(Charset::alpha(), length..=length).zip(just(length))
+        });
+        for _ in 0..100 {
+            let (string, length) = generator.generate();
+            assert_eq!(string.len(), length);
+        }
+    }
+
+    #[test]
+    fn can_generate_one_of_generators() {
+        let generator = one_of(vec![just(1), just(2)]);
+        let values: Vec<i32> = (0..100).map(|_| generator.generate()).collect();
+        assert!(values.contains(&1) && values.contains(&2));
+    }
+
+    #[test]
+    fn can_generate_elements_of() {
+        let generator = elements_of(vec!['a', 'b']);
+        assert!(['a', 'b'].contains(&generator.generate()));
+    }
+
+    #[test]
+    fn can_generate_same_values_with_same_rng() {
+        let generator = zip(some::<u64>(), bytes()).map(|(a, b)| a ^ b as u64);
+        let first = generator.generate_with(&mut StdRng::seed_from_u64(42));
+        let second = generator
+            .clone()
+            .generate_with(&mut StdRng::seed_from_u64(42));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn can_iterate_over_generator() {
+        let generator = numbers(0..10);
+        let values: Vec<u8> = generator.iter().take(100).collect();
+        assert_eq!(values.len(), 100);
+        assert!(values.iter().all(|value| *value < 10));
+        let mut rng = StdRng::seed_from_u64(42);
+        let first: Vec<u8> = generator.iter_with(&mut rng).take(10).collect();
+        let mut rng = StdRng::seed_from_u64(42);
+        let second: Vec<u8> = generator.iter_with(&mut rng).take(10).collect();
+        assert_eq!(first, second);
+    }
+}
@@ -12,10 +12,21 @@
 //! let value = some_number::<i32>(); // Generate some signed integer of 32 bytes alternative call
 //!
 //! some_number_between(5, 10); // Generate some signed integer between 5 and 10
+//! some_number_in(5..=10); // Generate some signed integer within any range, such as 5..10, 5..=10, ..10, -5.. or ..
+//! some_number_in::<f64, _>(..); // Generate some float over the whole finite range of the type
+//! some_float::<f64>(); // Generate some finite float, tiny and huge magnitudes alike
+//! some_float_of::<f32>(&[FloatClass::Nan, FloatClass::Infinite]); // Generate NaN or some infinity, see FloatClass for zeros, subnormals and values near EPSILON
 //! some_number_less_than(9); // Generates some signed integer less or equal to 9, can be less than 0 if of signed type
 //! some_number_greater_than(9); // Generates some number greater than 9
 //! some_negative_number::<isize>(); // Generates some negative number
 //! some_positive_number::<isize>(); // Generates some positive number
+//! some_numbers::<u32>().take(10); // Iterates over 10 numbers, some_numbers() never ends
+//! some_edgy_number::<i64>(); // Generates MIN, MIN + 1, -1, 0, 1, MAX - 1 or MAX half of the time, some number otherwise
+//! some_edgy_number_between(5, 10); // Generates 5, 6 or 9 half of the time, some number between 5 and 10 otherwise
+//! some_number_normal(100.0, 15.0); // Generate some float from a normal distribution, also see exponential, log_normal and pareto
+//! some_number_poisson(4.0); // Generate some count of events from a Poisson distribution, also see zipf
+//! try_some_number_between(10, 5); // Returns Err(rustyaid::Error::EmptyRange) instead of panicking
+//! try_some_number_greater_than(i32::MAX); // Returns Err(rustyaid::Error::Overflow) instead of overflowing
 //! ```
 //!
 //! # Byte
@@ -26,6 +37,58 @@
 //!
 //! some_byte(); // Returns a signgular byte (u8)
 //! some_byte_vector(1024); // Returns a vec containing 1024 random bytes
+//! fill_random_bytes(&mut [0u8; 4096]); // Overwrites a slice with random bytes, generated in blocks
+//! some_bytes().take(16); // Iterates over 16 random bytes, some_bytes() never ends
+//! ```
+//!
+//! # Collection
+//! The collection module provides methods to generate random collections and to pick random elements of slices
+//! ## Usage
+//! ```
+//! use rustyaid::collection::*;
+//! use rustyaid::number::some_number;
+//! use rustyaid::string::some_string;
+//!
+//! some_element_of(&["red", "green", "blue"]); // Picks one of the elements
+//! some_weighted_element_of(&[("GET", 8), ("POST", 2)]); // Picks "GET" 8 times out of 10
+//! some_subset_of(&[1, 2, 3]); // Picks each element with a probability of one half
+//! some_permutation_of(&[1, 2, 3]); // Shuffles the elements
+//! some_sample_of(&[1, 2, 3], 2); // Picks 2 elements at different positions
+//! some_vec_of(1..10, some_number::<u8>); // Generates a vec of 1 to 9 elements with the given function
+//! some_hash_map_of(5..=5, some_string, some_number::<u8>); // Generates a map of exactly 5 unique keys, also see some_hash_set_of, some_btree_map_of, some_btree_set_of and some_vec_deque_of
+//! ```
+//!
+//! # Generator
+//! The generator module provides generators, values wrapping the other methods so they can be passed around and combined
+//! ## Usage
+//! ```
+//! use rustyaid::generator::*;
+//! use rustyaid::string::Charset;
+//!
+//! let even = numbers(1..10).map(|n: u32| n * 2); // Generates even numbers between 2 and 18
+//! let names = strings().filter(|s| !s.trim().is_empty()); // Generates strings that are not blank
+//! let users = zip(numbers(1..1000), names); // Generates pairs of a number and a string
+//! let (id, name): (u32, String) = users.generate(); // Generates a value, generate_with(rng) draws it from rng
+//! let words = numbers(1..=5).flat_map(|n: usize| strings_from(Charset::alpha(), n..=n)); // Generates strings of a random length
+//! let status = one_of(vec![just(200), just(404), numbers(500..600)]); // Generates a value of one of the generators
+//! let statuses: Vec<u16> = status.iter().take(10).collect(); // Generates 10 values of the generator
+//! let codes = rustyaid::unique::unique(strings_from(Charset::alpha(), 3..=3)); // Wraps a generator to never repeat a value
+//! let code = codes.generate(); // Generates a code never generated before, try_generate() returns Err(rustyaid::Error::Exhausted) once all were generated
+//! rustyaid::unique::some_unique_string(); // Generates a string never generated before on the current thread
+//! rustyaid::unique::clear_unique_strings(); // Forgets the strings generated on the current thread, such as at the start of a test
+//! ```
+//!
+//! # Shrink
+//! The shrink module minimises failing values, so a randomized test reports the simplest value it fails for
+//! ## Usage
+//! ```
+//! use rustyaid::generator::*;
+//! use rustyaid::shrink::*;
+//!
+//! shrink(1234, |n| *n > 100); // Returns 101, the simplest number still failing
+//! shrink("hello world".to_string(), |s| s.contains('w')); // Returns "w", the shortest string still failing
+//! shrink(vec![9u8, 200, 3], |bytes| bytes.contains(&200)); // Returns vec![200], the fewest bytes still failing
+//! check(&strings(), 100, |s| s.chars().count() <= s.len()); // Checks the property for 100 strings, panicking with the shrunk value of the first failing one
 //! ```
 //!
 //! # String
@@ -36,6 +99,7 @@
 //!
 //! some_string(); // Generates a string of random length between 1 to 1024 characters
 //! some_string_of_length(32); // Generates a string of 32 characters
+//! some_strings().take(5); // Iterates over 5 strings generated like some_string(), some_strings() never ends
 //! some_string_of_length_between(10, 100); // Generates a string of random length between 10 and 100 characters
 //!
 //! some_alpha_string(); // Generates a string of random length between 1 to 1024 alphabet characters
@@ -49,13 +113,202 @@
 //! some_alphanumeric_string(); // Generates a string of random length between 1 to 1024 alphanumerical characters
 //! some_alphanumeric_string_of_length(32); // Generates a string of 32 alphanumerical characters
 //! some_alphanumeric_string_of_length_between(10, 100); // Generates a string of random length between 10 and 100 alphanumerical characters
+//!
+//! some_unicode_string(); // Generates a string of random length between 1 to 1024 Unicode characters
+//! some_unicode_string_of_length(32); // Generates a string of 32 Unicode characters, usually more bytes
+//! some_unicode_string_of_length_between(10, 100); // Generates a string of random length between 10 and 100 Unicode characters
+//!
+//! some_char(); // Generates a single character
+//!
+//! let hex = Charset::from('0'..='9').union('a'..='f'); // Builds a charset from ranges and lists of characters
+//! let ascii_without_quotes = Charset::ascii().exclude("\"'`"); // Excludes characters from a charset
+//! some_string_from(&hex, 10..=100); // Generates a string of random length between 10 and 100 hexadecimal characters
+//! some_string_from(&Charset::cjk().union(Charset::emoji()), 32..=32); // Generates a string of 32 CJK and emoji characters
+//!
+//! some_string_matching(r"[A-Z]{3}-\d{4}"); // Generates a string matched by the regular expression, such as "KQD-0417"
+//! some_string_from_pattern("###-???-**"); // Generates a string replacing # with numeric, ? with alpha and * with alphanumeric characters
+//!
+//! some_naughty_string(); // Generates a string known to break software, such as injection payloads or zero-width characters
+//! some_naughty_string_of(NaughtyCategory::SqlInjection); // Generates a naughty string of a specific category
+//! ```
+//!
+//! # Some
+//! The `Some` trait generates random values of any type implementing it, which includes all
+//! primitive numeric types, `bool`, `char`, `String`, `Vec<T>`, `Option<T>`, tuples and arrays.
+//! ## Usage
+//! ```
+//! use rustyaid::Some;
+//!
+//! let value = i32::some(); // Generate some signed integer of 32 bytes
+//! let value: (String, Option<u8>) = Some::some(); // Generate some tuple of a string and an optional byte
+//! let value = Vec::<String>::some(); // Generate some vec of between 1 and 32 strings
+//! ```
+//!
+//! `Some` can be derived for structs and enums, filling every field with a random value.
+//! ```
+//! use rustyaid::Some;
+//!
+//! #[derive(Some)]
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//!     role: Role,
+//! }
+//!
+//! #[derive(Some)]
+//! enum Role {
+//!     Admin,
+//!     Member { since: u32 },
+//! }
+//!
+//! let user = User::some(); // Generate some user with a random id, name and role
+//! ```
+//!
+//! Fields can be constrained with the `#[some(...)]` attribute.
+//! ```
+//! use rustyaid::rand::Rng;
+//! use rustyaid::Some;
+//!
+//! fn some_email<R: Rng + ?Sized>(rng: &mut R) -> String {
+//!     format!("user{}@example.com", rng.gen::<u16>())
+//! }
+//!
+//! #[derive(Some)]
+//! struct User {
+//!     #[some(range = 18..=65)] // Generates the field with some_number_in(18..=65)
+//!     age: u8,
+//!     #[some(alpha, len = 1..=32)] // Generates an alpha string of 1 to 32 characters
+//!     name: String,
+//!     #[some(numeric)] // Also supports alpha and alphanumeric, with or without len
+//!     phone: String,
+//!     #[some(with = some_email)] // Calls some_email(rng) to generate the field from the same random number generator
+//!     email: String,
+//!     #[some(default)] // Uses Default::default() for the field
+//!     deleted: bool,
+//! }
+//! ```
+//!
+//! Deriving `SomeBuilder` generates a fixture builder, pinning the fields that are set and
+//! generating all others randomly.
+//! ```
+//! use rustyaid::SomeBuilder;
+//!
+//! #[derive(SomeBuilder)]
+//! struct User {
+//!     id: u64,
+//!     #[some(alpha)]
+//!     name: String,
+//! }
+//!
+//! let user = User::some_builder().name("fixed").build(); // Generate some user named "fixed"
+//! ```
+//!
+//! # Seeding
+//! All values are drawn from a seeded random number generator local to the current thread.
+//! Setting the seed makes every value generated afterwards on that thread reproducible.
+//!
+//! Every test gets a fresh random seed, which is printed when the test panics.
+//! Running the tests with `RUSTYAID_SEED=<seed> cargo test` replays the exact same values.
+//! Seeds replay values of the same version of rustyaid only, a version changing how a generator
+//! draws its values, such as drawing all characters of a string at once, generates other values
+//! for the same seed.
+//! ## Usage
+//! ```
+//! use rustyaid::rng::*;
+//!
+//! set_seed(42); // Seeds the generator of the current thread
+//! seed(); // Returns the seed of the current thread, a random one is chosen if none was set
+//! ```
+//!
+//! Every generator also has a `_with` variant drawing its values from an explicit random number
+//! generator instead.
+//! ```
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//! use rustyaid::number::*;
+//! use rustyaid::string::*;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let value: i32 = some_number_with(&mut rng); // Generate some signed integer of 32 bytes from rng
+//! some_string_of_length_between_with(&mut rng, 10, 100); // Generates a string from rng
+//! ```
+//!
+//! Tests marked with `#[rustyaid::test]` can be repeated with a fresh seed for every iteration, the
+//! seed of a failing iteration is printed to be replayed.
+//! ```
+//! use rustyaid::number::*;
+//!
+//! #[rustyaid::test(iterations = 100)] // Runs the test 100 times, each with a different seed
+//! fn can_add_one() {
+//!     let n: i32 = some_number_less_than(i32::MAX);
+//!     assert!(n + 1 > n);
+//! }
+//!
+//! #[rustyaid::test(seed = 42)] // Runs the test once with a fixed seed
+//! fn can_add_two() {
+//!     let n: i32 = some_number_less_than(i32::MAX - 1);
+//!     assert!(n + 2 > n);
+//! }
 //! ```
 
+extern crate self as rustyaid;
+
 /// A simple random byte generator
 pub mod byte;
 
+/// Random collections and random selection of their elements
+pub mod collection;
+
+/// Errors returned by the fallible generators
+pub mod error;
+
+/// Composable generators of random values
+pub mod generator;
+
 /// A simple random number generator
 pub mod number;
 
+/// A seedable random number generator shared by all generators
+pub mod rng;
+
+/// Minimising of failing values and a lightweight property checker
+pub mod shrink;
+
+/// A trait for generating random values of any type
+pub mod some;
+
 /// A simple random string generator
 pub mod string;
+
+/// Generators never repeating a value
+pub mod unique;
+
+pub use crate::error::Error;
+pub use crate::some::Some;
+
+/// Derives the `Some` trait for a struct or enum
+///
+/// Every field is generated with its own `Some` implementation, for an enum a random variant is
+/// picked first.
+/// Fields accept `#[some(range = a..b)]`, or any other range accepted by `some_number_in()`,
+/// `#[some(len = a..b)]`, `#[some(alpha)]`, `#[some(numeric)]`, `#[some(alphanumeric)]`,
+/// `#[some(with = function)]` and `#[some(default)]` to constrain their values.
+/// The function of `with` takes the random number generator, `fn(&mut R) -> T` for any
+/// `R: Rng + ?Sized`, so values derived with `some_with()` stay reproducible.
+pub use rustyaid_macros::Some;
+
+/// Derives a fixture builder for a struct with named fields
+///
+/// Generates a `<Name>SomeBuilder` type, created with `Name::some_builder()`, with a setter for
+/// every field. Fields that are not set are generated randomly when calling `build()`, accepting
+/// the same `#[some(...)]` attributes as `#[derive(Some)]`.
+pub use rustyaid_macros::SomeBuilder;
+
+/// The version of the rand crate accepted by the `_with` generators
+pub use rand;
+
+/// Marks a function as a test whose values are drawn from a fresh seed
+///
+/// Runs the test through `rng::repeat_seeded()`, `iterations = N` repeats the test `N` times with
+/// different seeds and `seed = N` runs it once with a fixed seed.
+pub use rustyaid_macros::test;
@@ -1,14 +1,20 @@
-use num::{Bounded, FromPrimitive, Signed};
+use crate::error::{Error, Result};
+use crate::rng::seeded_rng;
+use num::{Bounded, Float, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::Standard;
 use rand::prelude::Distribution;
 use rand::Rng;
-use std::ops::{Add, Sub};
+use rand_distr::{Exp, LogNormal, Normal, Pareto, Poisson, Zipf};
+use std::cmp::Ordering;
+use std::iter;
+use std::ops::{Add, Bound, RangeBounds, Sub};
 
 /// Generates a new random number
 ///
 /// Generates a new number between the min and max values for the numerical type.
 /// Supports any primitive numeric type with finite bounds
+/// Floats are generated between `0` and `1`, use `some_float()` to cover their whole range
 ///
 /// # Examples
 ///
@@ -22,13 +28,1213 @@ where
     TYPE: Bounded,
     Standard: Distribution<TYPE>,
 {
-    let mut rng = rand::thread_rng();
+    some_number_with(&mut seeded_rng())
+}
+
+/// Generates a new random number using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_with(&mut rng);
+/// ```
+pub fn some_number_with<TYPE, R>(rng: &mut R) -> TYPE
+where
+    TYPE: Bounded,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
     rng.gen()
 }
 
-/// Generates a new random positive number
+/// Creates an infinite iterator of random numbers
+///
+/// Every item is generated like `some_number()`, drawing from the seeded random number generator of
+/// the current thread, so many values can be generated with `take()`, `zip()` and the other
+/// iterator adapters instead of a manual loop.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let values: Vec<u32> = some_numbers().take(10).collect();
+/// assert_eq!(values.len(), 10);
+/// ```
+pub fn some_numbers<TYPE>() -> impl Iterator<Item = TYPE>
+where
+    TYPE: Bounded,
+    Standard: Distribution<TYPE>,
+{
+    let mut rng = seeded_rng();
+    iter::repeat_with(move || some_number_with(&mut rng))
+}
+
+/// Creates an infinite iterator of random numbers using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_numbers()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let values: Vec<u32> = some_numbers_with(&mut rng).take(10).collect();
+/// ```
+pub fn some_numbers_with<TYPE, R>(rng: &mut R) -> impl Iterator<Item = TYPE> + '_
+where
+    TYPE: Bounded,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    iter::repeat_with(move || some_number_with(rng))
+}
+
+/// Generates a new random positive number
+///
+/// Generates a new number between the `0` and max values for the numerical type.
+/// Supports any primitive numeric type with finite bounds
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_positive_number();
+/// ```
+pub fn some_positive_number<TYPE>() -> TYPE
+where
+    TYPE: Bounded + FromPrimitive + PartialOrd + Add<Output = TYPE>,
+    Standard: Distribution<TYPE>,
+{
+    some_positive_number_with(&mut seeded_rng())
+}
+
+/// Generates a new random positive number using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_positive_number()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_positive_number_with(&mut rng);
+/// ```
+pub fn some_positive_number_with<TYPE, R>(rng: &mut R) -> TYPE
+where
+    TYPE: Bounded + FromPrimitive + PartialOrd + Add<Output = TYPE>,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    let number = some_number_with(rng);
+    if number <= TYPE::from_i8(0).unwrap() {
+        number.add(TYPE::max_value())
+    } else {
+        number
+    }
+}
+
+/// Generates a new random negative number
+///
+/// Generates a new number between the min and `0` for the numerical type.
+/// Supports any primitive numeric signed type with finite bounds
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_negative_number();
+/// ```
+pub fn some_negative_number<TYPE>() -> TYPE
+where
+    TYPE: Bounded + FromPrimitive + PartialOrd + Sub<Output = TYPE> + Signed,
+    Standard: Distribution<TYPE>,
+{
+    some_negative_number_with(&mut seeded_rng())
+}
+
+/// Generates a new random negative number using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_negative_number()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_negative_number_with(&mut rng);
+/// ```
+pub fn some_negative_number_with<TYPE, R>(rng: &mut R) -> TYPE
+where
+    TYPE: Bounded + FromPrimitive + PartialOrd + Sub<Output = TYPE> + Signed,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    let number = some_number_with(rng);
+    if number >= TYPE::from_i8(0).unwrap() {
+        number.neg()
+    } else {
+        number
+    }
+}
+
+/// Generates a new random number between `from`, inclusive, and `to`, exclusive
+///
+/// Generates a new number between the min and max values provided for the numerical type.
+/// Supports any primitive numeric type with finite bounds
+/// `some_number_in(from..to)` generates the same numbers and accepts any other kind of range
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_number_between(10, 20);
+/// ```
+pub fn some_number_between<TYPE>(from: TYPE, to: TYPE) -> TYPE
+where
+    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    some_number_between_with(&mut seeded_rng(), from, to)
+}
+
+/// Generates a new random number between `from` and `to`, exclusive, using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_between()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_between_with(&mut rng, 10, 20);
+/// ```
+pub fn some_number_between_with<TYPE, R>(rng: &mut R, from: TYPE, to: TYPE) -> TYPE
+where
+    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    match from.partial_cmp(&to) {
+        Some(Ordering::Less) => rng.gen_range(from..to),
+        Some(Ordering::Equal) => from,
+        _ => panic!("{}", Error::EmptyRange),
+    }
+}
+
+/// Tries to generate a new random number between `from`, inclusive, and `to`, exclusive
+///
+/// Works like `some_number_between()`, but returns an error instead of panicking
+/// when `from` is greater than `to` or either bound is infinite.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = try_some_number_between(10, 20).unwrap();
+/// assert!(try_some_number_between(20, 10).is_err());
+/// ```
+pub fn try_some_number_between<TYPE>(from: TYPE, to: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    try_some_number_between_with(&mut seeded_rng(), from, to)
+}
+
+/// Tries to generate a new random number between `from` and `to`, exclusive, using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `try_some_number_between()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = try_some_number_between_with(&mut rng, 10, 20).unwrap();
+/// ```
+pub fn try_some_number_between_with<TYPE, R>(rng: &mut R, from: TYPE, to: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    match from.partial_cmp(&to) {
+        Some(Ordering::Less) if !is_finite(from) || !is_finite(to) => Err(Error::UnsupportedBound),
+        Some(Ordering::Less) => Ok(sample_between(rng, from, to, false)),
+        Some(Ordering::Equal) => Ok(from),
+        _ => Err(Error::EmptyRange),
+    }
+}
+
+/// Generates a new random number between `from`, inclusive, and `to`, inclusive
+///
+/// Generates a new number between the min and max values provided for the numerical type.
+/// Supports any primitive numeric type with finite bounds
+/// `some_number_in(from..=to)` generates the same numbers and accepts any other kind of range
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_number_between_inclusive(10, 20);
+/// ```
+pub fn some_number_between_inclusive<TYPE>(from: TYPE, to: TYPE) -> TYPE
+where
+    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    some_number_between_inclusive_with(&mut seeded_rng(), from, to)
+}
+
+/// Generates a new random number between `from` and `to`, inclusive, using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_between_inclusive()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_between_inclusive_with(&mut rng, 10, 20);
+/// ```
+pub fn some_number_between_inclusive_with<TYPE, R>(rng: &mut R, from: TYPE, to: TYPE) -> TYPE
+where
+    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    match from.partial_cmp(&to) {
+        Some(Ordering::Less) => rng.gen_range(from..=to),
+        Some(Ordering::Equal) => from,
+        _ => panic!("{}", Error::EmptyRange),
+    }
+}
+
+/// Tries to generate a new random number between `from`, inclusive, and `to`, inclusive
+///
+/// Works like `some_number_between_inclusive()`, but returns an error instead of panicking
+/// when `from` is greater than `to` or either bound is infinite.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = try_some_number_between_inclusive(10, 20).unwrap();
+/// assert!(try_some_number_between_inclusive(20, 10).is_err());
+/// ```
+pub fn try_some_number_between_inclusive<TYPE>(from: TYPE, to: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    try_some_number_between_inclusive_with(&mut seeded_rng(), from, to)
+}
+
+/// Tries to generate a new random number between `from` and `to`, inclusive, using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `try_some_number_between_inclusive()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = try_some_number_between_inclusive_with(&mut rng, 10, 20).unwrap();
+/// ```
+pub fn try_some_number_between_inclusive_with<TYPE, R>(
+    rng: &mut R,
+    from: TYPE,
+    to: TYPE,
+) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    match from.partial_cmp(&to) {
+        Some(Ordering::Less) if !is_finite(from) || !is_finite(to) => Err(Error::UnsupportedBound),
+        Some(Ordering::Less) => Ok(sample_between(rng, from, to, true)),
+        Some(Ordering::Equal) => Ok(from),
+        _ => Err(Error::EmptyRange),
+    }
+}
+
+/// Generates a new random number greater than some value
+///
+/// Generates a new number between the provided minimum and the max value for the numerical type
+/// Supports any primitive numeric type with finite bounds
+/// Only supports generating positive numbers
+/// `some_number_in()` also accepts ranges starting at a negative value, such as `-10..`
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_number_greater_than(10);
+/// ```
+pub fn some_number_greater_than<TYPE>(bound: TYPE) -> TYPE
+where
+    TYPE: Bounded
+        + FromPrimitive
+        + PartialOrd
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    some_number_greater_than_with(&mut seeded_rng(), bound)
+}
+
+/// Generates a new random number greater than some value using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_greater_than()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_greater_than_with(&mut rng, 10);
+/// ```
+pub fn some_number_greater_than_with<TYPE, R>(rng: &mut R, bound: TYPE) -> TYPE
+where
+    TYPE: Bounded
+        + FromPrimitive
+        + PartialOrd
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    if bound < TYPE::from_i8(0).unwrap() {
+        panic!("{}", Error::UnsupportedBound)
+    }
+    if bound >= TYPE::max_value() {
+        panic!("{}", Error::Overflow)
+    }
+    some_number_between_with(rng, bound + TYPE::from_i8(1).unwrap(), TYPE::max_value())
+}
+
+/// Tries to generate a new random number greater than some value
+///
+/// Works like `some_number_greater_than()`, but returns an error instead of panicking
+/// when `bound` is negative or the max value for the numerical type.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = try_some_number_greater_than(10).unwrap();
+/// assert!(try_some_number_greater_than(-10).is_err());
+/// ```
+pub fn try_some_number_greater_than<TYPE>(bound: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    try_some_number_greater_than_with(&mut seeded_rng(), bound)
+}
+
+/// Tries to generate a new random number greater than some value using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `try_some_number_greater_than()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = try_some_number_greater_than_with(&mut rng, 10).unwrap();
+/// ```
+pub fn try_some_number_greater_than_with<TYPE, R>(rng: &mut R, bound: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    if bound < TYPE::from_i8(0).unwrap() {
+        return Err(Error::UnsupportedBound);
+    }
+    if bound >= TYPE::max_value() {
+        return Err(Error::Overflow);
+    }
+    try_some_number_between_with(rng, bound + TYPE::from_i8(1).unwrap(), TYPE::max_value())
+}
+
+/// Generates a new random number less than some value
+///
+/// Generates a new number between the the minimum value for the numerical type and the provided max value
+/// Supports any primitive numeric type with finite bounds
+/// Supports signed and unsigned types
+/// Can generate negative values
+/// Returns the min value itself when `bound` is the min value for the numerical type
+/// `some_number_in(..bound)` generates the same numbers and accepts any other kind of range
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_number_less_than(10);
+/// ```
+pub fn some_number_less_than<TYPE>(bound: TYPE) -> TYPE
+where
+    TYPE: Bounded
+        + FromPrimitive
+        + PartialOrd
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    some_number_less_than_with(&mut seeded_rng(), bound)
+}
+
+/// Generates a new random number less than some value using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_less_than()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_less_than_with(&mut rng, 10);
+/// ```
+pub fn some_number_less_than_with<TYPE, R>(rng: &mut R, bound: TYPE) -> TYPE
+where
+    TYPE: Bounded
+        + FromPrimitive
+        + PartialOrd
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    some_number_between_with(rng, TYPE::min_value(), bound)
+}
+
+/// Tries to generate a new random number less than some value
+///
+/// Works like `some_number_less_than()`, but returns an error instead of the min value
+/// when `bound` is the min value for the numerical type.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = try_some_number_less_than(10).unwrap();
+/// assert!(try_some_number_less_than(u32::MIN).is_err());
+/// ```
+pub fn try_some_number_less_than<TYPE>(bound: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+{
+    try_some_number_less_than_with(&mut seeded_rng(), bound)
+}
+
+/// Tries to generate a new random number less than some value using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `try_some_number_less_than()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = try_some_number_less_than_with(&mut rng, 10).unwrap();
+/// ```
+pub fn try_some_number_less_than_with<TYPE, R>(rng: &mut R, bound: TYPE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    if bound <= TYPE::min_value() {
+        return Err(Error::EmptyRange);
+    }
+    try_some_number_between_with(rng, TYPE::min_value(), bound)
+}
+
+/// Generates a new random number within a range
+///
+/// Generates a new number within any range of the numerical type, `a..b`, `a..=b`, `..b`, `..=b`,
+/// `a..` and `..`, where a missing bound stands for the min or max value of the type.
+/// Supports any primitive numeric type with finite bounds, including floats over their whole range
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = some_number_in(10..20);
+/// let n: i32 = some_number_in(-10..);
+/// let n: f64 = some_number_in(..=0.5);
+/// let n: f32 = some_number_in(..);
+/// ```
+pub fn some_number_in<TYPE, RANGE>(range: RANGE) -> TYPE
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    RANGE: RangeBounds<TYPE>,
+{
+    some_number_in_with(&mut seeded_rng(), range)
+}
+
+/// Generates a new random number within a range using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_in()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_number_in_with(&mut rng, 10..20);
+/// ```
+pub fn some_number_in_with<TYPE, RANGE, R>(rng: &mut R, range: RANGE) -> TYPE
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    RANGE: RangeBounds<TYPE>,
+    R: Rng + ?Sized,
+{
+    try_some_number_in_with(rng, range).unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// Tries to generate a new random number within a range
+///
+/// Works like `some_number_in()`, but returns an error instead of panicking when the range is
+/// empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: i32 = try_some_number_in(10..20).unwrap();
+/// assert!(try_some_number_in(10..10).is_err());
+/// ```
+pub fn try_some_number_in<TYPE, RANGE>(range: RANGE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    RANGE: RangeBounds<TYPE>,
+{
+    try_some_number_in_with(&mut seeded_rng(), range)
+}
+
+/// Tries to generate a new random number within a range using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `try_some_number_in()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = try_some_number_in_with(&mut rng, 10..20).unwrap();
+/// ```
+pub fn try_some_number_in_with<TYPE, RANGE, R>(rng: &mut R, range: RANGE) -> Result<TYPE>
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    RANGE: RangeBounds<TYPE>,
+    R: Rng + ?Sized,
+{
+    let (low, excluded_low) = match range.start_bound() {
+        Bound::Included(&low) => (low, false),
+        Bound::Excluded(&low) => (low, true),
+        Bound::Unbounded => (TYPE::min_value(), false),
+    };
+    let (high, included_high) = match range.end_bound() {
+        Bound::Included(&high) => (high, true),
+        Bound::Excluded(&high) => (high, false),
+        Bound::Unbounded => (TYPE::max_value(), true),
+    };
+    match low.partial_cmp(&high) {
+        Some(Ordering::Less) if !is_finite(low) || !is_finite(high) => {
+            return Err(Error::UnsupportedBound)
+        }
+        Some(Ordering::Less) => {}
+        Some(Ordering::Equal) if included_high && !excluded_low => return Ok(low),
+        _ => return Err(Error::EmptyRange),
+    }
+    if !excluded_low {
+        return Ok(sample_between(rng, low, high, included_high));
+    }
+    if !is_float::<TYPE>() {
+        // `low` is less than `high`, so its successor is at most `high` and cannot overflow
+        let successor = low + TYPE::one();
+        return match (successor < high, included_high) {
+            (true, _) => Ok(sample_between(rng, successor, high, included_high)),
+            (false, true) => Ok(high),
+            (false, false) => Err(Error::EmptyRange),
+        };
+    }
+    // There is no generic successor of `low` for floats, so values equal to it are rejected
+    // instead, which only keeps failing when nothing lies between `low` and `high`
+    for _ in 0..MAX_EXCLUDED_START_ATTEMPTS {
+        let number = sample_between(rng, low, high, included_high);
+        if number != low {
+            return Ok(number);
+        }
+    }
+    Err(Error::EmptyRange)
+}
+
+/// Draws a length within `length`, an unbounded end standing for `default_max`, or for the start
+/// when the start is greater
+///
+/// Shared by the generators of strings and collections, which only differ in their default maximum.
+pub(crate) fn try_some_length_in_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    length: impl RangeBounds<usize>,
+    default_max: usize,
+) -> Result<usize> {
+    let from = match length.start_bound() {
+        Bound::Included(&from) => from,
+        Bound::Excluded(&from) => from.checked_add(1).ok_or(Error::EmptyRange)?,
+        Bound::Unbounded => 0,
+    };
+    let to = match length.end_bound() {
+        Bound::Included(&to) => to,
+        Bound::Excluded(&to) => to.checked_sub(1).ok_or(Error::EmptyRange)?,
+        Bound::Unbounded => from.max(default_max),
+    };
+    try_some_number_between_inclusive_with(rng, from, to)
+}
+
+/// The number of times a float equal to an excluded start is drawn before the range is considered
+/// empty
+const MAX_EXCLUDED_START_ATTEMPTS: usize = 64;
+
+/// Samples a number between `low` and `high`, where `low < high`
+///
+/// The width of a float range such as `f64::MIN..f64::MAX` is too large for rand to sample from,
+/// such ranges are split in two halves, one of which is picked proportionally to its width.
+fn sample_between<TYPE, R>(rng: &mut R, low: TYPE, high: TYPE, included_high: bool) -> TYPE
+where
+    TYPE: Num + Bounded + FromPrimitive + ToPrimitive + PartialOrd + Copy + SampleUniform,
+    R: Rng + ?Sized,
+{
+    let two = TYPE::one() + TYPE::one();
+    if is_float::<TYPE>() && high - low > TYPE::max_value() / two {
+        let middle = low / two + high / two;
+        let lower = (middle - low).to_f64().unwrap() / 2.0;
+        let upper = (high - middle).to_f64().unwrap() / 2.0;
+        return if rng.gen_bool(lower / (lower + upper)) {
+            sample_between(rng, low, middle, false)
+        } else {
+            sample_between(rng, middle, high, included_high)
+        };
+    }
+    if included_high {
+        rng.gen_range(low..=high)
+    } else {
+        rng.gen_range(low..high)
+    }
+}
+
+/// Returns `false` for infinite floats, which cannot be sampled from
+fn is_finite<TYPE: ToPrimitive>(number: TYPE) -> bool {
+    number.to_f64().is_some_and(f64::is_finite)
+}
+
+/// Returns `true` when the numerical type can represent fractions
+fn is_float<TYPE: Num + FromPrimitive>() -> bool {
+    TYPE::from_f64(0.5).is_some_and(|half| !half.is_zero())
+}
+
+/// A class of floating-point values, see `some_float_of()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FloatClass {
+    /// Finite, non-zero normal values, generated like `some_float()`
+    Normal,
+    /// Positive and negative zero
+    Zero,
+    /// Positive and negative subnormal values, smaller than the smallest normal value
+    Subnormal,
+    /// Values close to `EPSILON`, such as `EPSILON`, `1 + EPSILON` and `1 - EPSILON`
+    NearEpsilon,
+    /// Positive and negative infinity
+    Infinite,
+    /// Not a number
+    Nan,
+}
+
+impl FloatClass {
+    /// All classes of floating-point values
+    pub const ALL: [FloatClass; 6] = [
+        FloatClass::Normal,
+        FloatClass::Zero,
+        FloatClass::Subnormal,
+        FloatClass::NearEpsilon,
+        FloatClass::Infinite,
+        FloatClass::Nan,
+    ];
+}
+
+/// Generates a new random finite float
+///
+/// Generates a new float between the min and max values for the floating-point type, unlike
+/// `some_number()` which only generates floats between `0` and `1`.
+/// The magnitudes are spread evenly over the exponents of the type, so tiny values such as `1e-300`
+/// are as likely as huge values such as `1e300`.
+/// Supports `f32` and `f64`
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_float();
+/// assert!(n.is_finite());
+/// ```
+pub fn some_float<TYPE>() -> TYPE
+where
+    TYPE: Float + SampleUniform,
+{
+    some_float_with(&mut seeded_rng())
+}
+
+/// Generates a new random finite float using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_float()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_float_with(&mut rng);
+/// ```
+pub fn some_float_with<TYPE, R>(rng: &mut R) -> TYPE
+where
+    TYPE: Float + SampleUniform,
+    R: Rng + ?Sized,
+{
+    let two = TYPE::one() + TYPE::one();
+    let min_exponent = binary_exponent(TYPE::min_positive_value());
+    let max_exponent = binary_exponent(TYPE::max_value());
+    let mantissa = rng.gen_range(TYPE::one()..two);
+    let exponent = rng.gen_range(min_exponent..=max_exponent);
+    with_some_sign(rng, mantissa * two.powi(exponent))
+}
+
+/// Generates a new random float of one of the provided classes
+///
+/// Picks one of `classes` at random, then generates a value of that class, letting numeric code be
+/// exercised with `NaN`, infinities, zeros, subnormals and values near `EPSILON` on request.
+/// Supports `f32` and `f64`
+///
+/// # Panics
+///
+/// Panics when `classes` is empty.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_float_of(&[FloatClass::Nan, FloatClass::Infinite]);
+/// assert!(n.is_nan() || n.is_infinite());
+/// let n: f32 = some_float_of(&FloatClass::ALL);
+/// ```
+pub fn some_float_of<TYPE>(classes: &[FloatClass]) -> TYPE
+where
+    TYPE: Float + SampleUniform,
+{
+    some_float_of_with(&mut seeded_rng(), classes)
+}
+
+/// Generates a new random float of one of the provided classes using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_float_of()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_float_of_with(&mut rng, &[FloatClass::Zero, FloatClass::Subnormal]);
+/// ```
+pub fn some_float_of_with<TYPE, R>(rng: &mut R, classes: &[FloatClass]) -> TYPE
+where
+    TYPE: Float + SampleUniform,
+    R: Rng + ?Sized,
+{
+    if classes.is_empty() {
+        panic!("Cannot generate a float of an empty list of classes")
+    }
+    match classes[rng.gen_range(0..classes.len())] {
+        FloatClass::Normal => some_float_with(rng),
+        FloatClass::Zero => with_some_sign(rng, TYPE::zero()),
+        FloatClass::Subnormal => {
+            let smallest = TYPE::min_positive_value() * TYPE::epsilon();
+            let number = TYPE::min_positive_value() * rng.gen_range(TYPE::zero()..TYPE::one());
+            with_some_sign(rng, number.max(smallest))
+        }
+        FloatClass::NearEpsilon => {
+            let epsilon = TYPE::epsilon();
+            let two = TYPE::one() + TYPE::one();
+            match rng.gen_range(0..5) {
+                0 => epsilon,
+                1 => -epsilon,
+                2 => TYPE::one() + epsilon,
+                3 => TYPE::one() - epsilon,
+                _ => rng.gen_range(epsilon / two..=epsilon * two),
+            }
+        }
+        FloatClass::Infinite => with_some_sign(rng, TYPE::infinity()),
+        FloatClass::Nan => TYPE::nan(),
+    }
+}
+
+/// Returns the exponent `e` of a positive normal float, such that `2^e <= number < 2^(e + 1)`
+fn binary_exponent<TYPE: Float>(number: TYPE) -> i32 {
+    let (mantissa, exponent, _) = number.integer_decode();
+    i32::from(exponent) + 63 - mantissa.leading_zeros() as i32
+}
+
+fn with_some_sign<TYPE: Float, R: Rng + ?Sized>(rng: &mut R, number: TYPE) -> TYPE {
+    if rng.gen() {
+        number.neg()
+    } else {
+        number
+    }
+}
+
+/// Generates a new random number from a normal distribution
+///
+/// Generates a new number from the normal, or Gaussian, distribution centred on `mean` with a
+/// standard deviation of `std_dev`.
+///
+/// # Panics
+///
+/// Panics when `std_dev` is not finite.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_number_normal(100.0, 15.0);
+/// ```
+pub fn some_number_normal(mean: f64, std_dev: f64) -> f64 {
+    some_number_normal_with(&mut seeded_rng(), mean, std_dev)
+}
+
+/// Generates a new random number from a normal distribution using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_normal()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_number_normal_with(&mut rng, 100.0, 15.0);
+/// ```
+pub fn some_number_normal_with<R: Rng + ?Sized>(rng: &mut R, mean: f64, std_dev: f64) -> f64 {
+    let distribution = Normal::new(mean, std_dev)
+        .unwrap_or_else(|error| panic!("Cannot generate a normally distributed number: {error}"));
+    rng.sample(distribution)
+}
+
+/// Generates a new random number from an exponential distribution
+///
+/// Generates a new positive number from the exponential distribution with a rate of `lambda`, such
+/// as the time between events happening `lambda` times per unit of time on average.
+///
+/// # Panics
+///
+/// Panics when `lambda` is negative.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_number_exponential(2.0);
+/// ```
+pub fn some_number_exponential(lambda: f64) -> f64 {
+    some_number_exponential_with(&mut seeded_rng(), lambda)
+}
+
+/// Generates a new random number from an exponential distribution using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_exponential()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_number_exponential_with(&mut rng, 2.0);
+/// ```
+pub fn some_number_exponential_with<R: Rng + ?Sized>(rng: &mut R, lambda: f64) -> f64 {
+    let distribution = Exp::new(lambda).unwrap_or_else(|error| {
+        panic!("Cannot generate an exponentially distributed number: {error}")
+    });
+    rng.sample(distribution)
+}
+
+/// Generates a new random number from a log-normal distribution
+///
+/// Generates a new positive number whose logarithm follows the normal distribution with a mean of
+/// `mu` and a standard deviation of `sigma`, a common model of latencies.
+///
+/// # Panics
+///
+/// Panics when `sigma` is not finite.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_number_log_normal(0.0, 0.5);
+/// ```
+pub fn some_number_log_normal(mu: f64, sigma: f64) -> f64 {
+    some_number_log_normal_with(&mut seeded_rng(), mu, sigma)
+}
+
+/// Generates a new random number from a log-normal distribution using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_log_normal()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_number_log_normal_with(&mut rng, 0.0, 0.5);
+/// ```
+pub fn some_number_log_normal_with<R: Rng + ?Sized>(rng: &mut R, mu: f64, sigma: f64) -> f64 {
+    let distribution = LogNormal::new(mu, sigma).unwrap_or_else(|error| {
+        panic!("Cannot generate a log-normally distributed number: {error}")
+    });
+    rng.sample(distribution)
+}
+
+/// Generates a new random number from a Poisson distribution
+///
+/// Generates a new count of events from the Poisson distribution, where `lambda` events happen on
+/// average, such as the number of requests arriving per second.
+///
+/// # Panics
+///
+/// Panics when `lambda` is not positive.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: u64 = some_number_poisson(4.0);
+/// ```
+pub fn some_number_poisson(lambda: f64) -> u64 {
+    some_number_poisson_with(&mut seeded_rng(), lambda)
+}
+
+/// Generates a new random number from a Poisson distribution using `rng`
 ///
-/// Generates a new number between the `0` and max values for the numerical type.
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_poisson()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: u64 = some_number_poisson_with(&mut rng, 4.0);
+/// ```
+pub fn some_number_poisson_with<R: Rng + ?Sized>(rng: &mut R, lambda: f64) -> u64 {
+    let distribution = Poisson::new(lambda)
+        .unwrap_or_else(|error| panic!("Cannot generate a Poisson distributed number: {error}"));
+    rng.sample(distribution) as u64
+}
+
+/// Generates a new random number from a Zipf distribution
+///
+/// Generates a new rank between `1` and `n`, both inclusive, where the rank `k` is drawn with a
+/// probability proportional to `1 / k^exponent`, such as the popularity of cached keys.
+///
+/// # Panics
+///
+/// Panics when `n` is `0` or `exponent` is negative.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: u64 = some_number_zipf(1000, 1.1);
+/// ```
+pub fn some_number_zipf(n: u64, exponent: f64) -> u64 {
+    some_number_zipf_with(&mut seeded_rng(), n, exponent)
+}
+
+/// Generates a new random number from a Zipf distribution using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_zipf()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: u64 = some_number_zipf_with(&mut rng, 1000, 1.1);
+/// ```
+pub fn some_number_zipf_with<R: Rng + ?Sized>(rng: &mut R, n: u64, exponent: f64) -> u64 {
+    let distribution = Zipf::new(n, exponent)
+        .unwrap_or_else(|error| panic!("Cannot generate a Zipf distributed number: {error}"));
+    rng.sample(distribution) as u64
+}
+
+/// Generates a new random number from a Pareto distribution
+///
+/// Generates a new number, greater than or equal to `scale`, from the heavy-tailed Pareto
+/// distribution, where a smaller `shape` makes large values more likely.
+///
+/// # Panics
+///
+/// Panics when `scale` or `shape` is not positive.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// let n: f64 = some_number_pareto(1.0, 1.16);
+/// ```
+pub fn some_number_pareto(scale: f64, shape: f64) -> f64 {
+    some_number_pareto_with(&mut seeded_rng(), scale, shape)
+}
+
+/// Generates a new random number from a Pareto distribution using `rng`
+///
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_number_pareto()` for the values it generates.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::number::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: f64 = some_number_pareto_with(&mut rng, 1.0, 1.16);
+/// ```
+pub fn some_number_pareto_with<R: Rng + ?Sized>(rng: &mut R, scale: f64, shape: f64) -> f64 {
+    let distribution = Pareto::new(scale, shape)
+        .unwrap_or_else(|error| panic!("Cannot generate a Pareto distributed number: {error}"));
+    rng.sample(distribution)
+}
+
+/// Generates a new random number, favouring boundary values
+///
+/// Generates a new number that is, half of the time, one of the boundary values of the numerical
+/// type, `MIN`, `MIN + 1`, `-1`, `0`, `1`, `MAX - 1` and `MAX`, mirroring what fuzzers do.
+/// The other half of the time the number is generated like `some_number()`.
 /// Supports any primitive numeric type with finite bounds
 ///
 /// # Examples
@@ -36,49 +1242,47 @@ where
 /// Basic usage:
 /// ```
 /// use rustyaid::number::*;
-/// let n: i32 = some_positive_number();
+/// let n: i64 = some_edgy_number();
 /// ```
-pub fn some_positive_number<TYPE>() -> TYPE
+pub fn some_edgy_number<TYPE>() -> TYPE
 where
-    TYPE: Bounded + FromPrimitive + PartialOrd + Add<Output = TYPE>,
+    TYPE: Bounded + Zero + One + PartialOrd + Copy + Add<Output = TYPE> + Sub<Output = TYPE>,
     Standard: Distribution<TYPE>,
 {
-    let number = some_number();
-    if number <= TYPE::from_i8(0).unwrap() {
-        number.add(TYPE::max_value())
-    } else {
-        number
-    }
+    some_edgy_number_with(&mut seeded_rng())
 }
 
-/// Generates a new random negative number
+/// Generates a new random number, favouring boundary values, using `rng`
 ///
-/// Generates a new number between the min and `0` for the numerical type.
-/// Supports any primitive numeric signed type with finite bounds
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_edgy_number()` for the values it generates.
 ///
 /// # Examples
 ///
 /// Basic usage:
 /// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
 /// use rustyaid::number::*;
-/// let n: i32 = some_negative_number();
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i64 = some_edgy_number_with(&mut rng);
 /// ```
-pub fn some_negative_number<TYPE>() -> TYPE
+pub fn some_edgy_number_with<TYPE, R>(rng: &mut R) -> TYPE
 where
-    TYPE: Bounded + FromPrimitive + PartialOrd + Sub<Output = TYPE> + Signed,
+    TYPE: Bounded + Zero + One + PartialOrd + Copy + Add<Output = TYPE> + Sub<Output = TYPE>,
     Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
 {
-    let number = some_number();
-    if number >= TYPE::from_i8(0).unwrap() {
-        number.neg()
-    } else {
-        number
-    }
+    let edges = edges_within(TYPE::min_value(), TYPE::max_value());
+    edge_or_else(rng, &edges, |rng| some_number_with(rng))
 }
 
-/// Generates a new random number between `from`, inclusive, and `to`, exclusive
+/// Generates a new random number between `from`, inclusive, and `to`, exclusive, favouring
+/// boundary values
 ///
-/// Generates a new number between the min and max values provided for the numerical type.
+/// Generates a new number that is, half of the time, one of the boundary values of the range,
+/// `from`, `from + 1`, `to - 1`, and `-1`, `0` and `1` when they are within the range.
+/// The other half of the time the number is generated like `some_number_between()`.
 /// Supports any primitive numeric type with finite bounds
 ///
 /// # Examples
@@ -86,106 +1290,168 @@ where
 /// Basic usage:
 /// ```
 /// use rustyaid::number::*;
-/// let n: i32 = some_number_between(10, 20);
+/// let n: i32 = some_edgy_number_between(10, 20);
 /// ```
-pub fn some_number_between<TYPE>(from: TYPE, to: TYPE) -> TYPE
+pub fn some_edgy_number_between<TYPE>(from: TYPE, to: TYPE) -> TYPE
 where
-    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    TYPE: FromPrimitive
+        + Zero
+        + One
+        + PartialOrd
+        + Copy
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
     Standard: Distribution<TYPE>,
 {
-    if from == to {
-        from
-    } else {
-        let mut rand_generator = rand::thread_rng();
-        rand_generator.gen_range(from..to)
-    }
+    some_edgy_number_between_with(&mut seeded_rng(), from, to)
 }
 
-/// Generates a new random number between `from`, inclusive, and `to`, inclusive
+/// Generates a new edgy random number between `from` and `to`, exclusive, using `rng`
 ///
-/// Generates a new number between the min and max values provided for the numerical type.
-/// Supports any primitive numeric type with finite bounds
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_edgy_number_between()` for the values it generates.
 ///
 /// # Examples
 ///
 /// Basic usage:
 /// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
 /// use rustyaid::number::*;
-/// let n: i32 = some_number_between_inclusive(10, 20);
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_edgy_number_between_with(&mut rng, 10, 20);
 /// ```
-pub fn some_number_between_inclusive<TYPE>(from: TYPE, to: TYPE) -> TYPE
+pub fn some_edgy_number_between_with<TYPE, R>(rng: &mut R, from: TYPE, to: TYPE) -> TYPE
 where
-    TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+    TYPE: FromPrimitive
+        + Zero
+        + One
+        + PartialOrd
+        + Copy
+        + Add<Output = TYPE>
+        + Sub<Output = TYPE>
+        + SampleUniform,
     Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
 {
-    if from == to {
-        from
-    } else {
-        let mut rand_generator = rand::thread_rng();
-        rand_generator.gen_range(from..=to)
+    if from >= to {
+        return some_number_between_with(rng, from, to);
     }
+    let mut edges = edges_within(from, to);
+    edges.retain(|edge| *edge < to);
+    edge_or_else(rng, &edges, |rng| some_number_between_with(rng, from, to))
 }
 
-/// Generates a new random number greater than some value
+/// Generates a new random number between `from`, inclusive, and `to`, inclusive, favouring
+/// boundary values
 ///
-/// Generates a new number between the provided minimum and the max value for the numerical type
+/// Generates a new number that is, half of the time, one of the boundary values of the range,
+/// `from`, `from + 1`, `to - 1`, `to`, and `-1`, `0` and `1` when they are within the range.
+/// The other half of the time the number is generated like `some_number_between_inclusive()`.
 /// Supports any primitive numeric type with finite bounds
-/// Only supports generating positive numbers
 ///
 /// # Examples
 ///
 /// Basic usage:
 /// ```
 /// use rustyaid::number::*;
-/// let n: i32 = some_number_greater_than(10);
+/// let n: i32 = some_edgy_number_between_inclusive(10, 20);
 /// ```
-pub fn some_number_greater_than<TYPE>(bound: TYPE) -> TYPE
+pub fn some_edgy_number_between_inclusive<TYPE>(from: TYPE, to: TYPE) -> TYPE
 where
-    TYPE: Bounded
-        + FromPrimitive
+    TYPE: FromPrimitive
+        + Zero
+        + One
         + PartialOrd
+        + Copy
         + Add<Output = TYPE>
         + Sub<Output = TYPE>
         + SampleUniform,
     Standard: Distribution<TYPE>,
 {
-    let zero = TYPE::from_i8(0).unwrap();
-    if bound < zero {
-        panic!("Cannot handle negative value")
-    }
-    some_number_between(bound + TYPE::from_i8(1).unwrap(), TYPE::max_value())
+    some_edgy_number_between_inclusive_with(&mut seeded_rng(), from, to)
 }
 
-/// Generates a new random number less than some value
+/// Generates a new edgy random number between `from` and `to`, inclusive, using `rng`
 ///
-/// Generates a new number between the the minimum value for the numerical type and the provided max value
-/// Supports any primitive numeric type with finite bounds
-/// Supports signed and unsigned types
-/// Can generate negative values
+/// Takes the value from `rng` rather than from the seeded random number generator of the current
+/// thread, see `some_edgy_number_between_inclusive()` for the values it generates.
 ///
 /// # Examples
 ///
 /// Basic usage:
 /// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
 /// use rustyaid::number::*;
-/// let n: i32 = some_number_less_than(10);
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n: i32 = some_edgy_number_between_inclusive_with(&mut rng, 10, 20);
 /// ```
-pub fn some_number_less_than<TYPE>(bound: TYPE) -> TYPE
+pub fn some_edgy_number_between_inclusive_with<TYPE, R>(rng: &mut R, from: TYPE, to: TYPE) -> TYPE
 where
-    TYPE: Bounded
-        + FromPrimitive
+    TYPE: FromPrimitive
+        + Zero
+        + One
         + PartialOrd
+        + Copy
         + Add<Output = TYPE>
         + Sub<Output = TYPE>
         + SampleUniform,
     Standard: Distribution<TYPE>,
+    R: Rng + ?Sized,
+{
+    if from >= to {
+        return some_number_between_inclusive_with(rng, from, to);
+    }
+    let edges = edges_within(from, to);
+    edge_or_else(rng, &edges, |rng| {
+        some_number_between_inclusive_with(rng, from, to)
+    })
+}
+
+/// The probability of the edgy generators returning a boundary value
+const EDGE_PROBABILITY: f64 = 0.5;
+
+/// Returns the boundary values within `from` and `to`, both inclusive, where `from < to`
+fn edges_within<TYPE>(from: TYPE, to: TYPE) -> Vec<TYPE>
+where
+    TYPE: Zero + One + PartialOrd + Copy + Add<Output = TYPE> + Sub<Output = TYPE>,
+{
+    let zero = TYPE::zero();
+    let one = TYPE::one();
+    let mut candidates = vec![from, from + one, to - one, to, zero, one];
+    if from < zero {
+        candidates.push(zero - one);
+    }
+    let mut edges = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if from <= candidate && candidate <= to && !edges.contains(&candidate) {
+            edges.push(candidate);
+        }
+    }
+    edges
+}
+
+fn edge_or_else<TYPE, R, F>(rng: &mut R, edges: &[TYPE], otherwise: F) -> TYPE
+where
+    TYPE: Copy,
+    R: Rng + ?Sized,
+    F: FnOnce(&mut R) -> TYPE,
 {
-    some_number_between(TYPE::min_value(), bound)
+    if !edges.is_empty() && rng.gen_bool(EDGE_PROBABILITY) {
+        edges[rng.gen_range(0..edges.len())]
+    } else {
+        otherwise(rng)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::ops::RangeInclusive;
 
     #[test]
     fn can_generate_random_signed_integers() {
@@ -275,4 +1541,361 @@ mod tests {
         let actual = some_number_greater_than(from);
         assert!(actual > from);
     }
+
+    #[test]
+    fn can_generate_same_numbers_from_same_rng() {
+        let mut first = StdRng::seed_from_u64(42);
+        let mut second = StdRng::seed_from_u64(42);
+        assert_eq!(
+            some_number_with::<i64, _>(&mut first),
+            some_number_with::<i64, _>(&mut second)
+        );
+        assert_eq!(
+            some_number_between_with(&mut first, -1000, 1000),
+            some_number_between_with(&mut second, -1000, 1000)
+        );
+    }
+
+    #[test]
+    fn can_generate_signed_integer_between_with_rng() {
+        let mut rng = StdRng::seed_from_u64(some_number());
+        let from = -1000;
+        let to = 1000;
+        let actual = some_number_between_with(&mut rng, from, to);
+        assert!(actual >= from);
+        assert!(actual < to);
+    }
+
+    #[test]
+    fn can_generate_edgy_numbers_of_type() {
+        let values: Vec<i64> = (0..1000).map(|_| some_edgy_number()).collect();
+        for edge in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX] {
+            assert!(values.contains(&edge), "{edge} was never generated");
+        }
+    }
+
+    #[test]
+    fn can_generate_edgy_unsigned_numbers_of_type() {
+        let values: Vec<u8> = (0..1000).map(|_| some_edgy_number()).collect();
+        for edge in [0, 1, u8::MAX - 1, u8::MAX] {
+            assert!(values.contains(&edge), "{edge} was never generated");
+        }
+    }
+
+    #[test]
+    fn can_generate_edgy_numbers_between() {
+        let values: Vec<i32> = (0..1000)
+            .map(|_| some_edgy_number_between(-50, 50))
+            .collect();
+        assert!(values.iter().all(|value| (-50..50).contains(value)));
+        for edge in [-50, -49, -1, 0, 1, 49] {
+            assert!(values.contains(&edge), "{edge} was never generated");
+        }
+    }
+
+    #[test]
+    fn can_generate_edgy_numbers_between_inclusive() {
+        let values: Vec<u32> = (0..1000)
+            .map(|_| some_edgy_number_between_inclusive(10, 20))
+            .collect();
+        assert!(values.iter().all(|value| (10..=20).contains(value)));
+        for edge in [10, 11, 19, 20] {
+            assert!(values.contains(&edge), "{edge} was never generated");
+        }
+    }
+
+    #[test]
+    fn can_generate_edgy_floats_between() {
+        let values: Vec<f64> = (0..1000)
+            .map(|_| some_edgy_number_between(0.0, 0.5))
+            .collect();
+        assert!(values.iter().all(|value| (0.0..0.5).contains(value)));
+        assert!(values.contains(&0.0));
+    }
+
+    #[test]
+    fn can_try_to_generate_number_between() {
+        let actual = try_some_number_between(10, 20).unwrap();
+        assert!((10..20).contains(&actual));
+        assert_eq!(try_some_number_between(10, 10), Ok(10));
+        assert_eq!(try_some_number_between_inclusive(20, 20), Ok(20));
+    }
+
+    #[test]
+    fn cannot_generate_number_between_reversed_bounds() {
+        assert_eq!(try_some_number_between(20, 10), Err(Error::EmptyRange));
+        assert_eq!(
+            try_some_number_between_inclusive(20.0, 10.0),
+            Err(Error::EmptyRange)
+        );
+        assert_eq!(
+            try_some_number_between(f64::NAN, 10.0),
+            Err(Error::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn cannot_generate_number_greater_than_unsupported_bound() {
+        assert_eq!(
+            try_some_number_greater_than(-1),
+            Err(Error::UnsupportedBound)
+        );
+        assert_eq!(try_some_number_greater_than(u8::MAX), Err(Error::Overflow));
+        assert_eq!(try_some_number_greater_than(u8::MAX - 1), Ok(u8::MAX));
+    }
+
+    #[test]
+    fn cannot_generate_number_less_than_min() {
+        assert_eq!(try_some_number_less_than(i8::MIN), Err(Error::EmptyRange));
+        assert_eq!(try_some_number_less_than(0u16), Err(Error::EmptyRange));
+        assert_eq!(try_some_number_less_than(1u16), Ok(0));
+    }
+
+    fn some_number_between_generic<TYPE>(from: TYPE, to: TYPE) -> TYPE
+    where
+        TYPE: FromPrimitive + PartialOrd + Sub<Output = TYPE> + SampleUniform,
+        Standard: Distribution<TYPE>,
+    {
+        some_number_between(from, to)
+    }
+
+    #[test]
+    fn can_generate_number_between_with_original_bounds() {
+        assert!((10..20).contains(&some_number_between_generic(10, 20)));
+    }
+
+    #[test]
+    fn can_generate_min_number_less_than_min_without_try() {
+        assert_eq!(some_number_less_than(0u32), 0);
+        assert_eq!(some_number_less_than(i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn can_generate_float_between_whole_range() {
+        for _ in 0..100 {
+            let number = try_some_number_between(f64::MIN, f64::MAX).unwrap();
+            assert!(number.is_finite());
+            let number = try_some_number_between_inclusive(f32::MIN, f32::MAX).unwrap();
+            assert!(number.is_finite());
+        }
+    }
+
+    #[test]
+    fn cannot_generate_float_between_infinite_bounds() {
+        assert_eq!(
+            try_some_number_between(0.0, f64::INFINITY),
+            Err(Error::UnsupportedBound)
+        );
+        assert_eq!(
+            try_some_number_between_inclusive(f32::NEG_INFINITY, 0.0),
+            Err(Error::UnsupportedBound)
+        );
+        assert_eq!(
+            try_some_number_in(0.0..f64::INFINITY),
+            Err(Error::UnsupportedBound)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn cannot_generate_number_between_reversed_bounds_without_try() {
+        some_number_between(20, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond the maximum")]
+    fn cannot_generate_number_greater_than_max_without_try() {
+        some_number_greater_than(i32::MAX);
+    }
+
+    #[test]
+    fn can_generate_number_in_range() {
+        for _ in 0..100 {
+            assert!((10..20).contains(&some_number_in(10..20)));
+            assert!((10..=20).contains(&some_number_in(10..=20)));
+            assert!((-10..).contains(&some_number_in(-10..)));
+            assert!((..-10).contains(&some_number_in(..-10)));
+            assert!((..=5u8).contains(&some_number_in(..=5u8)));
+            assert!((-1.0..1.0).contains(&some_number_in(-1.0..1.0)));
+        }
+        assert_eq!(some_number_in(7..=7), 7);
+        let _: u128 = some_number_in(..);
+    }
+
+    #[test]
+    fn can_generate_float_in_whole_range() {
+        let values: Vec<f64> = (0..1000).map(|_| some_number_in(..)).collect();
+        assert!(values.iter().all(|value| value.is_finite()));
+        assert!(values.iter().any(|value| *value > 1e300));
+        assert!(values.iter().any(|value| *value < -1e300));
+        let value: f32 = some_number_in(0.0..);
+        assert!(value.is_finite() && value >= 0.0);
+    }
+
+    #[test]
+    fn can_generate_number_in_range_with_excluded_start() {
+        let range = (Bound::Excluded(1), Bound::Included(2));
+        for _ in 0..100 {
+            assert_eq!(some_number_in(range), 2);
+        }
+        let value = some_number_in((Bound::Excluded(0.0), Bound::Excluded(1.0)));
+        assert!(value > 0.0 && value < 1.0);
+    }
+
+    #[test]
+    fn can_generate_integer_after_excluded_start_without_sampling() {
+        let range = (Bound::Excluded(0u8), Bound::Included(1u8));
+        for _ in 0..1000 {
+            assert_eq!(try_some_number_in(range), Ok(1));
+        }
+        assert_eq!(
+            try_some_number_in((Bound::Excluded(i64::MAX - 1), Bound::Unbounded)),
+            Ok(i64::MAX)
+        );
+        assert_eq!(
+            try_some_number_in((Bound::Excluded(4u32), Bound::Excluded(5u32))),
+            Err(Error::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn cannot_generate_number_in_empty_range() {
+        assert_eq!(try_some_number_in(10..10), Err(Error::EmptyRange));
+        assert_eq!(
+            try_some_number_in(RangeInclusive::new(20, 10)),
+            Err(Error::EmptyRange)
+        );
+        assert_eq!(try_some_number_in(..0u32), Err(Error::EmptyRange));
+        assert_eq!(
+            try_some_number_in((Bound::Excluded(i8::MAX), Bound::Unbounded)),
+            Err(Error::EmptyRange)
+        );
+        assert_eq!(
+            try_some_number_in((Bound::Excluded(1), Bound::Excluded(2))),
+            Err(Error::EmptyRange)
+        );
+        assert_eq!(try_some_number_in(f64::NAN..1.0), Err(Error::EmptyRange));
+    }
+
+    #[test]
+    fn can_generate_float_over_whole_range() {
+        let values: Vec<f64> = (0..1000).map(|_| some_float()).collect();
+        assert!(values.iter().all(|value| value.is_normal()));
+        assert!(values.iter().any(|value| value.abs() > 1e100));
+        assert!(values.iter().any(|value| value.abs() < 1e-100));
+        assert!(values.iter().any(|value| *value < 0.0));
+        let values: Vec<f32> = (0..1000).map(|_| some_float()).collect();
+        assert!(values.iter().all(|value| value.is_normal()));
+    }
+
+    #[test]
+    fn can_generate_float_of_class() {
+        for _ in 0..100 {
+            assert!(some_float_of::<f64>(&[FloatClass::Normal]).is_normal());
+            assert_eq!(some_float_of::<f64>(&[FloatClass::Zero]), 0.0);
+            assert!(some_float_of::<f32>(&[FloatClass::Subnormal]).is_subnormal());
+            assert!(some_float_of::<f64>(&[FloatClass::Subnormal]).is_subnormal());
+            assert!(some_float_of::<f64>(&[FloatClass::Infinite]).is_infinite());
+            assert!(some_float_of::<f64>(&[FloatClass::Nan]).is_nan());
+            let near_epsilon: f64 = some_float_of(&[FloatClass::NearEpsilon]);
+            assert!(
+                near_epsilon.abs() <= 2.0 * f64::EPSILON
+                    || (near_epsilon - 1.0).abs() <= f64::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn can_generate_float_of_every_class() {
+        let values: Vec<f64> = (0..1000).map(|_| some_float_of(&FloatClass::ALL)).collect();
+        assert!(values.iter().any(|value| value.is_nan()));
+        assert!(values.contains(&f64::INFINITY));
+        assert!(values.contains(&f64::NEG_INFINITY));
+        assert!(values.iter().any(|value| value.is_subnormal()));
+        assert!(values
+            .iter()
+            .any(|value| *value == 0.0 && value.is_sign_negative()));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty list of classes")]
+    fn cannot_generate_float_of_no_class() {
+        some_float_of::<f64>(&[]);
+    }
+
+    fn mean(values: &[f64]) -> f64 {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+
+    #[test]
+    fn can_generate_normally_distributed_numbers() {
+        let values: Vec<f64> = (0..10_000)
+            .map(|_| some_number_normal(100.0, 15.0))
+            .collect();
+        assert!((mean(&values) - 100.0).abs() < 1.0);
+        assert!(values.iter().any(|value| *value < 85.0));
+        assert!(values.iter().any(|value| *value > 115.0));
+    }
+
+    #[test]
+    fn can_generate_exponentially_distributed_numbers() {
+        let values: Vec<f64> = (0..10_000).map(|_| some_number_exponential(2.0)).collect();
+        assert!(values.iter().all(|value| *value >= 0.0));
+        assert!((mean(&values) - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn can_generate_log_normally_distributed_numbers() {
+        let values: Vec<f64> = (0..1000)
+            .map(|_| some_number_log_normal(0.0, 0.5))
+            .collect();
+        assert!(values.iter().all(|value| *value > 0.0));
+    }
+
+    #[test]
+    fn can_generate_poisson_distributed_numbers() {
+        let values: Vec<f64> = (0..10_000)
+            .map(|_| some_number_poisson(4.0) as f64)
+            .collect();
+        assert!((mean(&values) - 4.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn can_generate_zipf_distributed_numbers() {
+        let values: Vec<u64> = (0..1000).map(|_| some_number_zipf(100, 1.1)).collect();
+        assert!(values.iter().all(|value| (1..=100).contains(value)));
+        let ones = values.iter().filter(|value| **value == 1).count();
+        let fifties = values.iter().filter(|value| **value == 50).count();
+        assert!(ones > fifties);
+    }
+
+    #[test]
+    fn can_generate_pareto_distributed_numbers() {
+        let values: Vec<f64> = (0..1000).map(|_| some_number_pareto(2.0, 1.16)).collect();
+        assert!(values.iter().all(|value| *value >= 2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot generate a normally distributed number")]
+    fn cannot_generate_normally_distributed_number_with_infinite_std_dev() {
+        some_number_normal(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot generate a Zipf distributed number")]
+    fn cannot_generate_zipf_distributed_number_without_ranks() {
+        some_number_zipf(0, 1.0);
+    }
+
+    #[test]
+    fn can_generate_iterator_of_numbers() {
+        let values: Vec<u64> = some_numbers().take(100).collect();
+        assert_eq!(values.len(), 100);
+        assert!(values.iter().any(|value| *value != values[0]));
+        let mut rng = StdRng::seed_from_u64(42);
+        let first: Vec<i16> = some_numbers_with(&mut rng).take(10).collect();
+        let mut rng = StdRng::seed_from_u64(42);
+        let second: Vec<i16> = some_numbers_with(&mut rng).take(10).collect();
+        assert_eq!(first, second);
+    }
 }
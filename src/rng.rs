@@ -0,0 +1,331 @@
+use rand::rngs::StdRng;
+use rand::{Error, RngCore, SeedableRng};
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+/// The environment variable used to replay the values of a previous run
+///
+/// When set to an unsigned 64 bit integer, every thread starts generating values from that seed
+/// instead of a random one.
+/// A seed only replays the values of the version of rustyaid that printed it.
+pub const SEED_VARIABLE: &str = "RUSTYAID_SEED";
+
+thread_local! {
+    static SEEDED_RNG: RefCell<Option<Seeded>> = const { RefCell::new(None) };
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+struct Seeded {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Seeded {
+    fn new(seed: u64) -> Self {
+        report_seed_on_panic();
+        Seeded {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+fn with_seeded<T>(f: impl FnOnce(&mut Seeded) -> T) -> T {
+    SEEDED_RNG.with(|cell| {
+        let mut seeded = cell.borrow_mut();
+        f(seeded.get_or_insert_with(|| Seeded::new(initial_seed())))
+    })
+}
+
+fn initial_seed() -> u64 {
+    env_seed().unwrap_or_else(rand::random)
+}
+
+fn env_seed() -> Option<u64> {
+    env::var(SEED_VARIABLE).ok().map(|value| {
+        parse_seed(&value).unwrap_or_else(|| {
+            panic!("{SEED_VARIABLE} must be an unsigned 64 bit integer, got '{value}'")
+        })
+    })
+}
+
+fn parse_seed(value: &str) -> Option<u64> {
+    value.trim().parse().ok()
+}
+
+fn current_seed() -> Option<u64> {
+    SEEDED_RNG
+        .try_with(|cell| {
+            cell.try_borrow()
+                .ok()
+                .and_then(|seeded| seeded.as_ref().map(|seeded| seeded.seed))
+        })
+        .ok()
+        .flatten()
+}
+
+fn report_seed_on_panic() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if QUIET_PANICS.try_with(Cell::get).unwrap_or(false) {
+                return;
+            }
+            previous(info);
+            if let Some(seed) = current_seed() {
+                eprintln!(
+                    "rustyaid: values were generated with seed {seed}, \
+                     rerun with {SEED_VARIABLE}={seed} to replay them"
+                );
+            }
+        }));
+    });
+}
+
+/// Runs `f`, catching its panic without printing it
+///
+/// Used for panics that are expected and reported in another way, such as the panics of a property
+/// while shrinking the value it fails for.
+pub(crate) fn catch_quiet_panic<T>(f: impl FnOnce() -> T) -> thread::Result<T> {
+    report_seed_on_panic();
+    let quiet = QUIET_PANICS.with(|quiet| quiet.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET_PANICS.with(|cell| cell.set(quiet));
+    result
+}
+
+/// Seeds the random number generator of the current thread
+///
+/// Resets the random number generator used by all `some_*` functions on the current thread so that
+/// every value generated afterwards is derived from `seed`.
+/// Setting the same seed again replays exactly the same sequence of values.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// use rustyaid::rng::*;
+///
+/// set_seed(42);
+/// let first: u64 = some_number();
+/// set_seed(42);
+/// let second: u64 = some_number();
+/// assert_eq!(first, second);
+/// ```
+pub fn set_seed(seed: u64) {
+    SEEDED_RNG.with(|cell| *cell.borrow_mut() = Some(Seeded::new(seed)));
+}
+
+/// Returns the seed of the random number generator of the current thread
+///
+/// Returns the seed last passed to `set_seed()` on the current thread.
+/// When no seed has been set, the seed is read from the `RUSTYAID_SEED` environment variable, or
+/// chosen at random when the variable is not set, the first time a value is generated.
+///
+/// The test harness runs every test on its own thread, so every test gets a fresh seed.
+/// When a thread panics after generating values its seed is printed to stderr, running the tests
+/// again with `RUSTYAID_SEED` set to that seed replays exactly the same values.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::rng::*;
+///
+/// set_seed(42);
+/// assert_eq!(seed(), 42);
+/// ```
+pub fn seed() -> u64 {
+    with_seeded(|seeded| seeded.seed)
+}
+
+/// Runs a test repeatedly, each iteration with a fresh seed
+///
+/// Seeds the random number generator of the current thread with a new random seed before each of
+/// the `iterations` runs of `test`.
+/// When the `RUSTYAID_SEED` environment variable is set, `test` is only run once, with that seed.
+/// When an iteration panics, the failing iteration is printed to stderr along with its seed and
+/// the panic is resumed.
+///
+/// This is what the `#[rustyaid::test]` attribute expands to.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::number::*;
+/// use rustyaid::rng::*;
+///
+/// repeat_seeded(10, || {
+///     let n: u8 = some_number();
+///     assert!(u16::from(n) < 256);
+/// });
+/// ```
+pub fn repeat_seeded<F: FnMut()>(iterations: usize, test: F) {
+    repeat_with_seeds(seeds_for(iterations, env_seed()), test)
+}
+
+/// Returns the seeds of `iterations` runs, or only `replayed` when a seed is replayed
+fn seeds_for(iterations: usize, replayed: Option<u64>) -> Vec<u64> {
+    match replayed {
+        Some(seed) => vec![seed],
+        None => (0..iterations).map(|_| rand::random()).collect(),
+    }
+}
+
+/// Runs `test` once for every seed, reporting the failing iteration before resuming its panic
+fn repeat_with_seeds<F: FnMut()>(seeds: Vec<u64>, mut test: F) {
+    let total = seeds.len();
+    for (iteration, seed) in seeds.into_iter().enumerate() {
+        set_seed(seed);
+        if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(&mut test)) {
+            eprintln!("rustyaid: iteration {} of {total} failed", iteration + 1);
+            panic::resume_unwind(panic);
+        }
+    }
+}
+
+/// Returns a handle to the seeded random number generator of the current thread
+///
+/// The returned `SeededRng` implements `rand::RngCore`, every value it produces is drawn from the
+/// same thread local generator used by all `some_*` functions and is therefore controlled by
+/// `set_seed()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::Rng;
+/// use rustyaid::rng::*;
+///
+/// let n: u8 = seeded_rng().gen();
+/// ```
+pub fn seeded_rng() -> SeededRng {
+    SeededRng { _private: () }
+}
+
+/// A handle to the seeded random number generator of the current thread
+///
+/// Created with `rng::seeded_rng()`.
+/// The handle holds no state itself, so it is cheap to create and can be created as often as
+/// needed.
+#[derive(Clone, Copy, Debug)]
+pub struct SeededRng {
+    _private: (),
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        with_seeded(|seeded| seeded.rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        with_seeded(|seeded| seeded.rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        with_seeded(|seeded| seeded.rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        with_seeded(|seeded| seeded.rng.try_fill_bytes(dest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::byte::some_byte_vector;
+    use crate::number::some_number;
+    use crate::string::some_string;
+
+    #[test]
+    fn can_get_seed_that_was_set() {
+        set_seed(1234);
+        assert_eq!(seed(), 1234);
+    }
+
+    #[test]
+    fn can_get_seed_without_setting_one() {
+        assert_eq!(seed(), seed());
+    }
+
+    #[test]
+    fn can_parse_seed() {
+        assert_eq!(parse_seed("1234"), Some(1234));
+        assert_eq!(parse_seed(" 1234\n"), Some(1234));
+    }
+
+    #[test]
+    fn cannot_parse_invalid_seed() {
+        assert_eq!(parse_seed("-1"), None);
+        assert_eq!(parse_seed("seed"), None);
+    }
+
+    #[test]
+    fn can_repeat_test_for_every_iteration() {
+        let mut iterations = 0;
+        repeat_with_seeds(seeds_for(5, None), || iterations += 1);
+        assert_eq!(iterations, 5);
+    }
+
+    #[test]
+    fn can_repeat_test_with_different_seeds() {
+        let mut seeds = Vec::new();
+        repeat_with_seeds(seeds_for(5, None), || seeds.push(seed()));
+        seeds.dedup();
+        assert_eq!(seeds.len(), 5);
+    }
+
+    #[test]
+    fn can_replay_test_once_with_replayed_seed() {
+        assert_eq!(seeds_for(5, Some(7)), vec![7]);
+        let mut seeds = Vec::new();
+        repeat_with_seeds(seeds_for(5, Some(7)), || seeds.push(seed()));
+        assert_eq!(seeds, vec![7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "failing iteration")]
+    fn can_resume_panic_of_failing_iteration() {
+        repeat_seeded(5, || panic!("failing iteration"));
+    }
+
+    #[crate::test(iterations = 3)]
+    fn can_repeat_test_with_attribute() {
+        let n: u8 = some_number();
+        assert!(u16::from(n) < 256);
+    }
+
+    #[crate::test(seed = 1234)]
+    fn can_seed_test_with_attribute() {
+        assert_eq!(seed(), 1234);
+    }
+
+    #[test]
+    fn can_replay_values_with_same_seed() {
+        set_seed(1234);
+        let number = some_number::<i64>();
+        let string = some_string();
+        let bytes = some_byte_vector(64);
+
+        set_seed(1234);
+        assert_eq!(some_number::<i64>(), number);
+        assert_eq!(some_string(), string);
+        assert_eq!(some_byte_vector(64), bytes);
+    }
+
+    #[test]
+    fn can_generate_different_values_with_different_seeds() {
+        set_seed(1);
+        let first = some_byte_vector(64);
+        set_seed(2);
+        let second = some_byte_vector(64);
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,349 @@
+use crate::generator::Generator;
+use crate::rng::{catch_quiet_panic, seeded_rng};
+use rand::Rng;
+use std::any::Any;
+use std::fmt::Debug;
+use std::iter;
+use std::ops::Range;
+
+/// The number of times `shrink()` replaces a failing value with a simpler one before stopping
+const MAX_SHRINK_STEPS: usize = 10_000;
+
+/// A type whose values can be replaced with simpler values
+///
+/// Implemented for all primitive numeric types, which shrink toward zero, `bool`, which shrinks
+/// toward `false`, `String` and `Vec<T>`, which shrink toward fewer and simpler elements, and
+/// pairs.
+/// Used by `shrink()` and `check()` to minimise the values failing a test.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::shrink::*;
+///
+/// assert_eq!(100.shrink().collect::<Vec<_>>(), vec![0, 50, 75, 88, 94, 97, 99]);
+/// assert!("abc".to_string().shrink().any(|s| s == "ab"));
+/// ```
+pub trait Shrink: Sized {
+    /// Returns values simpler than this value, the simplest first
+    ///
+    /// The values are created lazily, so finding the first one that still fails a test does not
+    /// create the others.
+    /// Returns no values when this value cannot be simplified.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_>;
+}
+
+macro_rules! impl_shrink_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Shrink for $t {
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+                    if *self == 0 {
+                        return Box::new(iter::empty());
+                    }
+                    // Halving the distance to zero finds the smallest failing value in a
+                    // logarithmic number of steps
+                    let distances = iter::successors(Some(*self / 2), |distance| Some(distance / 2))
+                        .take_while(|distance| *distance != 0);
+                    Box::new(iter::once(0).chain(distances.map(move |distance| *self - distance)))
+                }
+            }
+        )*
+    };
+}
+
+impl_shrink_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_shrink_float {
+    ($($t:ty),*) => {
+        $(
+            impl Shrink for $t {
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+                    if *self == 0.0 {
+                        return Box::new(iter::empty());
+                    }
+                    if !self.is_finite() {
+                        return Box::new(iter::once(0.0));
+                    }
+                    let mut candidates = vec![0.0, self.trunc(), *self / 2.0];
+                    candidates.dedup();
+                    candidates.retain(|candidate| candidate != self);
+                    Box::new(candidates.into_iter())
+                }
+            }
+        )*
+    };
+}
+
+impl_shrink_float!(f32, f64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        Box::new(self.then_some(false).into_iter())
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        let offsets: Vec<usize> = self
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(iter::once(self.len()))
+            .collect();
+        Box::new(removals(offsets.len() - 1).map(move |removed| {
+            let mut string = String::with_capacity(self.len());
+            string.push_str(&self[..offsets[removed.start]]);
+            string.push_str(&self[offsets[removed.end]..]);
+            string
+        }))
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        let shorter = removals(self.len()).map(|removed| {
+            let mut vec = Vec::with_capacity(self.len() - removed.len());
+            vec.extend_from_slice(&self[..removed.start]);
+            vec.extend_from_slice(&self[removed.end..]);
+            vec
+        });
+        let simpler = self.iter().enumerate().flat_map(move |(index, element)| {
+            element.shrink().map(move |simpler| {
+                let mut vec = self.clone();
+                vec[index] = simpler;
+                vec
+            })
+        });
+        Box::new(shorter.chain(simpler))
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        let firsts = self.0.shrink().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().map(|b| (self.0.clone(), b));
+        Box::new(firsts.chain(seconds))
+    }
+}
+
+/// Returns the ranges to remove from `length` elements, all of them first, then chunks of half,
+/// a quarter and so on down to single elements
+fn removals(length: usize) -> impl Iterator<Item = Range<usize>> {
+    let everything = (length > 0).then_some(0..length);
+    let chunks =
+        iter::successors(Some(length / 2), |chunk| Some(chunk / 2)).take_while(|chunk| *chunk > 0);
+    everything.into_iter().chain(chunks.flat_map(move |chunk| {
+        (0..length)
+            .step_by(chunk)
+            .map(move |start| start..(start + chunk).min(length))
+    }))
+}
+
+/// Minimises a value for which `fails` returns `true`
+///
+/// Repeatedly replaces `value` with the first of its `Shrink::shrink()` candidates that still
+/// fails, until none of the candidates fails, returning the simplest failing value found.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::shrink::*;
+///
+/// assert_eq!(shrink(1234, |n| *n > 100), 101);
+/// assert_eq!(shrink("hello world".to_string(), |s| s.contains('w')), "w");
+/// ```
+pub fn shrink<T: Shrink>(value: T, fails: impl Fn(&T) -> bool) -> T {
+    let mut value = value;
+    for _ in 0..MAX_SHRINK_STEPS {
+        let simpler = value.shrink().find(|candidate| fails(candidate));
+        match simpler {
+            Some(simpler) => value = simpler,
+            None => break,
+        }
+    }
+    value
+}
+
+/// Checks that a property holds for many values of a generator
+///
+/// Generates `iterations` values with `generator` and passes each to `property`, which returns
+/// `false` or panics, such as with a failing `assert!`, when the property does not hold.
+/// The first failing value is minimised with `shrink()` before failing the test, so the reported
+/// value is as simple as possible.
+///
+/// The value is shrunk without knowing the generator, so it can be shrunk to a value the generator
+/// never produces, such as `0` for `numbers(500..600)` or `""` for strings of at least one
+/// character.
+/// A property relying on such bounds should hold for the values outside of them, as shown below,
+/// so shrinking keeps to values the generator could produce.
+///
+/// # Panics
+///
+/// Panics with the original and the minimised value when the property does not hold for a value.
+/// The seed printed along with it replays the same values.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// use rustyaid::shrink::*;
+/// use rustyaid::string::Charset;
+///
+/// check(&strings(), 100, |s| s.chars().rev().collect::<String>().len() == s.len());
+/// let numeric = strings_from(Charset::numeric(), 1..=5);
+/// check(&numeric, 100, |s| s.is_empty() || s.parse::<u32>().is_ok());
+/// ```
+pub fn check<T: Shrink + Clone + Debug + 'static>(
+    generator: &Generator<T>,
+    iterations: usize,
+    property: impl Fn(&T) -> bool,
+) {
+    check_with(&mut seeded_rng(), generator, iterations, property)
+}
+
+/// Checks that a property holds for many values of a generator using the provided random number
+/// generator
+///
+/// Works like `check()`, but draws the values from `rng` instead of the seeded random number
+/// generator of the current thread.
+///
+/// # Panics
+///
+/// Panics with the original and the minimised value when the property does not hold for a value.
+/// Unlike with `check()`, the printed seed does not replay the values, seeding `rng` the same way
+/// does.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::generator::*;
+/// use rustyaid::shrink::*;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// check_with(&mut rng, &numbers(0..1000), 100, |n: &u32| n * 2 >= *n);
+/// ```
+pub fn check_with<T: Shrink + Clone + Debug + 'static, R: Rng + ?Sized>(
+    rng: &mut R,
+    generator: &Generator<T>,
+    iterations: usize,
+    property: impl Fn(&T) -> bool,
+) {
+    let fails = |value: &T| !catch_quiet_panic(|| property(value)).unwrap_or(false);
+    for iteration in 1..=iterations {
+        let value = generator.generate_with(rng);
+        if fails(&value) {
+            let minimal = shrink(value.clone(), fails);
+            let reason = match catch_quiet_panic(|| property(&minimal)) {
+                Err(panic) => format!(", panicking with '{}'", panic_message(panic.as_ref())),
+                Ok(_) => String::new(),
+            };
+            panic!(
+                "Property failed for {minimal:?}{reason}, shrunk from {value:?}, in iteration \
+                 {iteration} of {iterations}"
+            )
+        }
+    }
+}
+
+/// Returns the message of a panic raised with `panic!()` or `assert!()`
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    match panic.downcast_ref::<String>() {
+        Some(message) => message,
+        None => panic
+            .downcast_ref::<&str>()
+            .copied()
+            .unwrap_or("no message"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{bytes, numbers, strings, zip};
+
+    #[test]
+    fn can_shrink_numbers_toward_zero() {
+        assert_eq!(10u8.shrink().collect::<Vec<_>>(), vec![0, 5, 8, 9]);
+        assert_eq!((-10i32).shrink().collect::<Vec<_>>(), vec![0, -5, -8, -9]);
+        assert_eq!(1i64.shrink().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(0u32.shrink().count(), 0);
+        assert_eq!(2.5f64.shrink().collect::<Vec<_>>(), vec![0.0, 2.0, 1.25]);
+        assert_eq!(f32::NAN.shrink().collect::<Vec<_>>(), vec![0.0]);
+    }
+
+    #[test]
+    fn can_shrink_strings_to_fewer_characters() {
+        let actual: Vec<String> = "abcd".to_string().shrink().collect();
+        assert_eq!(actual, vec!["", "cd", "ab", "bcd", "acd", "abd", "abc"]);
+        assert_eq!(
+            "äö".to_string().shrink().collect::<Vec<_>>(),
+            vec!["", "ö", "ä"]
+        );
+        assert_eq!(String::new().shrink().count(), 0);
+    }
+
+    #[test]
+    fn can_shrink_vec_to_fewer_and_smaller_elements() {
+        let actual: Vec<Vec<u8>> = vec![3u8, 4].shrink().collect();
+        assert_eq!(actual[0], Vec::<u8>::new());
+        assert!(actual.contains(&vec![4]));
+        assert!(actual.contains(&vec![0, 4]));
+    }
+
+    #[test]
+    fn can_shrink_failing_values() {
+        assert_eq!(shrink(u64::MAX, |n| *n >= 1000), 1000);
+        assert_eq!(shrink(1_000_000u64, |n| *n >= 500_001), 500_001);
+        assert_eq!(shrink(-5000i32, |n| *n < -10), -11);
+        assert_eq!(
+            shrink(vec![9u8, 200, 3, 150], |v| v.iter().any(|b| *b > 100)),
+            vec![101]
+        );
+        assert_eq!(shrink("xyz?!".to_string(), |s| s.contains('!')), "!");
+        assert_eq!(shrink((7u8, true), |(n, _)| *n > 3), (4, false));
+    }
+
+    #[test]
+    fn can_shrink_large_byte_vector() {
+        let bytes: Vec<u8> = (0..1_000_000).map(|index| (index % 251) as u8).collect();
+        assert_eq!(shrink(bytes, |v| v.contains(&250)), vec![250]);
+    }
+
+    #[test]
+    fn can_check_property_holding() {
+        check(&numbers(0..100), 100, |n: &u32| n + 1 > *n);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property failed for 50")]
+    fn can_report_shrunk_value_of_failing_property() {
+        check(&numbers(0..1_000_000), 100, |n: &u32| *n < 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property failed for \"\"")]
+    fn can_report_shrunk_string_of_failing_property() {
+        check(&strings(), 100, |s| s.len() > 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property failed for (100, 100)")]
+    fn can_report_shrunk_pair_of_failing_property() {
+        check(&zip(bytes(), bytes()), 1000, |(a, b)| *a < 100 || *b < 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property failed for 550, panicking with 'too large'")]
+    fn can_report_shrunk_value_of_panicking_property() {
+        check(&numbers(500..600), 1000, |n: &u32| {
+            assert!(!(500..600).contains(n) || *n < 550, "too large");
+            true
+        });
+    }
+}
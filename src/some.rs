@@ -0,0 +1,385 @@
+use crate::number::{some_float_with, some_number_between_inclusive_with, some_number_with};
+use crate::rng::seeded_rng;
+use crate::string::{some_char_with, some_string_with};
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+const DEFAULT_MIN_LENGTH: usize = 1;
+const DEFAULT_MAX_LENGTH: usize = 32;
+
+/// A type that can generate random values of itself
+///
+/// Implemented for all primitive numeric types, `bool`, `char`, `String`, `Vec<T>`, `Option<T>`,
+/// `Box<T>`, the standard collections, tuples of up to 12 elements and arrays, letting generic test
+/// helpers ask for `T::some()` instead of a differently named function for every type.
+///
+/// Integers are generated with `number::some_number()`, floats over their whole finite range with
+/// `number::some_float()`, characters and strings with `string::some_char()` and
+/// `string::some_string()`.
+/// A `Vec<T>` holds between 1 and 32 elements and an `Option<T>` is `None` half of the time.
+/// `VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>`, `HashMap<K, V>` and `BTreeMap<K, V>` are collected
+/// from such a `Vec`, so sets and maps hold fewer elements when generated elements repeat, use the
+/// `collection` module to generate them with an exact number of unique elements.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::Some;
+///
+/// let n = i32::some();
+/// let pair: (u8, String) = Some::some();
+/// let names = Vec::<String>::some();
+/// ```
+pub trait Some: Sized {
+    /// Generates a new random value
+    ///
+    /// Draws the value from the seeded random number generator of the current thread.
+    fn some() -> Self {
+        Self::some_with(&mut seeded_rng())
+    }
+
+    /// Generates a new random value using the provided random number generator
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+macro_rules! impl_some_number {
+    ($($t:ty),*) => {
+        $(
+            impl Some for $t {
+                fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    some_number_with(rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_some_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_some_float {
+    ($($t:ty),*) => {
+        $(
+            impl Some for $t {
+                fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    some_float_with(rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_some_float!(f32, f64);
+
+impl Some for bool {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl Some for char {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        some_char_with(rng)
+    }
+}
+
+impl Some for String {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        some_string_with(rng)
+    }
+}
+
+impl<T: Some> Some for Vec<T> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let bound = some_number_between_inclusive_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH);
+        (0..bound).map(|_| T::some_with(rng)).collect()
+    }
+}
+
+impl<T: Some> Some for VecDeque<T> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Vec::some_with(rng).into()
+    }
+}
+
+impl<T: Some + Eq + Hash, S: BuildHasher + Default> Some for HashSet<T, S> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Vec::some_with(rng).into_iter().collect()
+    }
+}
+
+impl<T: Some + Ord> Some for BTreeSet<T> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Vec::some_with(rng).into_iter().collect()
+    }
+}
+
+impl<K: Some + Eq + Hash, V: Some, S: BuildHasher + Default> Some for HashMap<K, V, S> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Vec::<(K, V)>::some_with(rng).into_iter().collect()
+    }
+}
+
+impl<K: Some + Ord, V: Some> Some for BTreeMap<K, V> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Vec::<(K, V)>::some_with(rng).into_iter().collect()
+    }
+}
+
+impl<T: Some> Some for Option<T> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        if rng.gen() {
+            Option::Some(T::some_with(rng))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Some> Some for Box<T> {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Box::new(T::some_with(rng))
+    }
+}
+
+impl<T: Some, const N: usize> Some for [T; N] {
+    fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        std::array::from_fn(|_| T::some_with(rng))
+    }
+}
+
+impl Some for () {
+    fn some_with<R: Rng + ?Sized>(_rng: &mut R) -> Self {}
+}
+
+macro_rules! impl_some_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: Some),*> Some for ($($t,)*) {
+            fn some_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                ($($t::some_with(rng),)*)
+            }
+        }
+    };
+}
+
+impl_some_tuple!(A);
+impl_some_tuple!(A, B);
+impl_some_tuple!(A, B, C);
+impl_some_tuple!(A, B, C, D);
+impl_some_tuple!(A, B, C, D, E);
+impl_some_tuple!(A, B, C, D, E, F);
+impl_some_tuple!(A, B, C, D, E, F, G);
+impl_some_tuple!(A, B, C, D, E, F, G, H);
+impl_some_tuple!(A, B, C, D, E, F, G, H, I);
+impl_some_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_some_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_some_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn can_generate_some_numbers() {
+        let _ = i8::some();
+        let _ = u128::some();
+        let _ = usize::some();
+        let _ = f64::some();
+    }
+
+    #[test]
+    fn can_generate_some_floats_beyond_unit_interval() {
+        let floats: Vec<f64> = (0..100).map(|_| f64::some()).collect();
+        assert!(floats.iter().all(|float| float.is_finite()));
+        assert!(floats.iter().any(|float| !(0.0..1.0).contains(float)));
+    }
+
+    #[test]
+    fn can_generate_some_string() {
+        let actual = String::some();
+        assert!(!actual.is_empty());
+    }
+
+    #[test]
+    fn can_generate_some_vec() {
+        let actual = Vec::<u8>::some();
+        assert!(actual.len() >= DEFAULT_MIN_LENGTH);
+        assert!(actual.len() <= DEFAULT_MAX_LENGTH);
+    }
+
+    #[test]
+    fn can_generate_some_collections() {
+        assert!(!VecDeque::<u8>::some().is_empty());
+        assert!(!HashSet::<u32>::some().is_empty());
+        assert!(!BTreeSet::<bool>::some().is_empty());
+        let map = HashMap::<String, u8>::some();
+        assert!((DEFAULT_MIN_LENGTH..=DEFAULT_MAX_LENGTH).contains(&map.len()));
+        assert!(!BTreeMap::<u64, String>::some().is_empty());
+    }
+
+    #[test]
+    fn can_generate_some_option() {
+        let values: Vec<Option<bool>> = (0..100).map(|_| Some::some()).collect();
+        assert!(values.iter().any(Option::is_some));
+        assert!(values.iter().any(Option::is_none));
+    }
+
+    #[test]
+    fn can_generate_some_tuple_and_array() {
+        let (_, _, _): (char, bool, Box<i32>) = Some::some();
+        let array = <[u8; 16]>::some();
+        assert_eq!(array.len(), 16);
+    }
+
+    #[test]
+    fn can_generate_same_value_from_same_rng() {
+        let seed = u64::some();
+        let first = <(String, Vec<i64>)>::some_with(&mut StdRng::seed_from_u64(seed));
+        let second = <(String, Vec<i64>)>::some_with(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(first, second);
+    }
+
+    #[derive(Debug, PartialEq, crate::Some)]
+    struct Named {
+        id: u64,
+        name: String,
+        tags: Vec<char>,
+    }
+
+    #[derive(Debug, PartialEq, crate::Some)]
+    struct Tuple(i32, Option<bool>);
+
+    #[derive(Debug, PartialEq, crate::Some)]
+    struct Generic<T> {
+        value: T,
+    }
+
+    #[derive(Debug, PartialEq, crate::Some)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rectangle { width: u32, height: u32 },
+    }
+
+    #[test]
+    fn can_derive_some_struct() {
+        let actual = Named::some();
+        assert!(!actual.name.is_empty());
+        let _ = Tuple::some();
+        let _ = Generic::<String>::some();
+    }
+
+    #[test]
+    fn can_derive_some_enum() {
+        let shapes: Vec<Shape> = (0..100).map(|_| Shape::some()).collect();
+        assert!(shapes.contains(&Shape::Empty));
+        assert!(shapes.iter().any(|shape| matches!(shape, Shape::Circle(_))));
+        assert!(shapes
+            .iter()
+            .any(|shape| matches!(shape, Shape::Rectangle { .. })));
+    }
+
+    #[test]
+    fn can_derive_same_value_from_same_rng() {
+        let seed = u64::some();
+        let first = Named::some_with(&mut StdRng::seed_from_u64(seed));
+        let second = Named::some_with(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(first, second);
+    }
+
+    fn some_email<R: Rng + ?Sized>(rng: &mut R) -> String {
+        format!(
+            "{}@example.com",
+            crate::string::some_alpha_string_of_length_with(rng, 8)
+        )
+    }
+
+    #[derive(Debug, crate::Some)]
+    struct Constrained {
+        #[some(range = 18..=65)]
+        age: u8,
+        #[some(range = -1.0..1.0)]
+        ratio: f64,
+        #[some(range = 1..)]
+        id: i64,
+        #[some(len = 5..=10)]
+        code: String,
+        #[some(alpha)]
+        name: String,
+        #[some(numeric, len = 3..4)]
+        pin: String,
+        #[some(with = some_email)]
+        email: String,
+        #[some(default)]
+        deleted: bool,
+    }
+
+    #[test]
+    fn can_derive_some_struct_with_constrained_fields() {
+        let actual = Constrained::some();
+        assert!((18..=65).contains(&actual.age));
+        assert!((-1.0..1.0).contains(&actual.ratio));
+        assert!(actual.id >= 1);
+        assert!((5..=10).contains(&actual.code.len()));
+        assert!(!actual.name.is_empty());
+        assert_eq!(actual.pin.len(), 3);
+        assert!(actual.pin.chars().all(|c| c.is_ascii_digit()));
+        assert!(actual.email.ends_with("@example.com"));
+        assert!(!actual.deleted);
+    }
+
+    #[derive(Debug, PartialEq, crate::SomeBuilder)]
+    struct Fixture<T> {
+        id: u64,
+        #[some(alpha, len = 8..=8)]
+        name: String,
+        value: T,
+    }
+
+    #[test]
+    fn can_derive_same_constrained_value_from_same_rng() {
+        let seed = u64::some();
+        let first = Constrained::some_with(&mut StdRng::seed_from_u64(seed));
+        let second = Constrained::some_with(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(first.email, second.email);
+    }
+
+    #[test]
+    fn can_build_fixture_with_overridden_fields() {
+        let actual = Fixture::<bool>::some_builder()
+            .name("fixed")
+            .value(true)
+            .build();
+        assert_eq!(actual.name, "fixed");
+        assert!(actual.value);
+    }
+
+    #[test]
+    fn can_build_fixture_with_generated_fields() {
+        let actual = Fixture::<String>::some_builder().id(42).build();
+        assert_eq!(actual.id, 42);
+        assert_eq!(actual.name.len(), 8);
+        assert!(!actual.value.is_empty());
+    }
+
+    #[test]
+    fn can_build_same_fixture_from_same_rng() {
+        let seed = u64::some();
+        let first = Fixture::<i8>::some_builder().build_with(&mut StdRng::seed_from_u64(seed));
+        let second = Fixture::<i8>::some_builder().build_with(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(first, second);
+    }
+
+    fn generic_helper<T: Some>() -> T {
+        T::some()
+    }
+
+    #[test]
+    fn can_generate_some_value_in_generic_helper() {
+        let _: u32 = generic_helper();
+        let _: String = generic_helper();
+    }
+}
@@ -1,4 +1,18 @@
-use crate::number::{some_number_between, some_number_less_than};
+use crate::number::{
+    some_number_between_with, some_number_less_than_with, try_some_length_in_with,
+};
+use crate::rng::seeded_rng;
+use rand::distributions::Uniform;
+use rand::Rng;
+use std::iter;
+use std::ops::RangeBounds;
+
+mod charset;
+mod naughty;
+mod regex;
+
+pub use self::charset::Charset;
+pub use self::naughty::NaughtyCategory;
 
 const ALPHA_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
                             abcdefghijklmnopqrstuvwxyz ";
@@ -38,7 +52,64 @@ const DEFAULT_MAX_LENGTH: usize = 1024;
 /// let s = some_string();
 /// ```
 pub fn some_string() -> String {
-    some_string_of_length_between(DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+    some_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random characters using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_string_with(&mut rng);
+/// ```
+pub fn some_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    some_string_of_length_between_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+}
+
+/// Creates an infinite iterator of random strings
+///
+/// Every item is generated like `some_string()`, drawing from the seeded random number generator of
+/// the current thread, so many values can be generated with `take()`, `zip()` and the other
+/// iterator adapters instead of a manual loop.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let values: Vec<String> = some_strings().take(10).collect();
+/// assert_eq!(values.len(), 10);
+/// ```
+pub fn some_strings() -> impl Iterator<Item = String> {
+    let mut rng = seeded_rng();
+    iter::repeat_with(move || some_string_with(&mut rng))
+}
+
+/// Creates an infinite iterator of random strings using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_strings()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let values: Vec<String> = some_strings_with(&mut rng).take(10).collect();
+/// ```
+pub fn some_strings_with<R: Rng + ?Sized>(rng: &mut R) -> impl Iterator<Item = String> + '_ {
+    iter::repeat_with(move || some_string_with(rng))
 }
 
 /// Creates a new string of random characters for a set length
@@ -63,7 +134,26 @@ pub fn some_string() -> String {
 /// let s = some_string_of_length(50);
 /// ```
 pub fn some_string_of_length(to_bound: usize) -> String {
-    some_string_of_length_between(to_bound, to_bound)
+    some_string_of_length_with(&mut seeded_rng(), to_bound)
+}
+
+/// Creates a new string of random characters for a set length using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string_of_length()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_string_of_length_with(&mut rng, 50);
+/// ```
+pub fn some_string_of_length_with<R: Rng + ?Sized>(rng: &mut R, to_bound: usize) -> String {
+    some_string_of_length_between_with(rng, to_bound, to_bound)
 }
 
 /// Creates a new string of random characters for a length between two values
@@ -88,8 +178,31 @@ pub fn some_string_of_length(to_bound: usize) -> String {
 /// let s = some_string_of_length_between(50, 100);
 /// ```
 pub fn some_string_of_length_between(from_bound: usize, to_bound: usize) -> String {
-    let bound = some_number_between(from_bound, to_bound);
-    string_for_charset(bound, ASCII_CHARSET)
+    some_string_of_length_between_with(&mut seeded_rng(), from_bound, to_bound)
+}
+
+/// Creates a new string of random characters for a length between two values using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string_of_length_between()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_string_of_length_between_with(&mut rng, 50, 100);
+/// ```
+pub fn some_string_of_length_between_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    from_bound: usize,
+    to_bound: usize,
+) -> String {
+    let bound = some_number_between_with(rng, from_bound, to_bound);
+    string_for_charset(rng, bound, ASCII_CHARSET)
 }
 
 /// Creates a new string of random alphanumeric characters
@@ -112,7 +225,26 @@ pub fn some_string_of_length_between(from_bound: usize, to_bound: usize) -> Stri
 /// let s = some_alphanumeric_string();
 /// ```
 pub fn some_alphanumeric_string() -> String {
-    some_alphanumeric_string_of_length_between(DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+    some_alphanumeric_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random alphanumeric characters using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alphanumeric_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alphanumeric_string_with(&mut rng);
+/// ```
+pub fn some_alphanumeric_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    some_alphanumeric_string_of_length_between_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
 }
 
 /// Creates a new string of random alphanumeric characters for a set length
@@ -135,7 +267,29 @@ pub fn some_alphanumeric_string() -> String {
 /// let s = some_alphanumeric_string_of_length(50);
 /// ```
 pub fn some_alphanumeric_string_of_length(to_bound: usize) -> String {
-    some_alphanumeric_string_of_length_between(to_bound, to_bound)
+    some_alphanumeric_string_of_length_with(&mut seeded_rng(), to_bound)
+}
+
+/// Creates a new string of random alphanumeric characters for a set length using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alphanumeric_string_of_length()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alphanumeric_string_of_length_with(&mut rng, 50);
+/// ```
+pub fn some_alphanumeric_string_of_length_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    to_bound: usize,
+) -> String {
+    some_alphanumeric_string_of_length_between_with(rng, to_bound, to_bound)
 }
 
 /// Creates a new string of random alphanumeric characters for a length between two values
@@ -158,8 +312,31 @@ pub fn some_alphanumeric_string_of_length(to_bound: usize) -> String {
 /// let s = some_alpha_string_of_length_between(50, 100);
 /// ```
 pub fn some_alphanumeric_string_of_length_between(from_bound: usize, to_bound: usize) -> String {
-    let bound = some_number_between(from_bound, to_bound);
-    string_for_charset(bound, ALPHANUMERIC_CHARSET)
+    some_alphanumeric_string_of_length_between_with(&mut seeded_rng(), from_bound, to_bound)
+}
+
+/// Creates a new alphanumeric string for a length between two values using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alphanumeric_string_of_length_between()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alphanumeric_string_of_length_between_with(&mut rng, 50, 100);
+/// ```
+pub fn some_alphanumeric_string_of_length_between_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    from_bound: usize,
+    to_bound: usize,
+) -> String {
+    let bound = some_number_between_with(rng, from_bound, to_bound);
+    string_for_charset(rng, bound, ALPHANUMERIC_CHARSET)
 }
 
 /// Creates a new string of random numeric characters
@@ -178,7 +355,26 @@ pub fn some_alphanumeric_string_of_length_between(from_bound: usize, to_bound: u
 /// let s = some_numeric_string();
 /// ```
 pub fn some_numeric_string() -> String {
-    some_numeric_string_of_length_between(DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+    some_numeric_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random numeric characters using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_numeric_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_numeric_string_with(&mut rng);
+/// ```
+pub fn some_numeric_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    some_numeric_string_of_length_between_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
 }
 
 /// Creates a new string of random numeric characters for a set length
@@ -197,7 +393,26 @@ pub fn some_numeric_string() -> String {
 /// let s = some_numeric_string_of_length(50);
 /// ```
 pub fn some_numeric_string_of_length(to_bound: usize) -> String {
-    some_numeric_string_of_length_between(to_bound, to_bound)
+    some_numeric_string_of_length_with(&mut seeded_rng(), to_bound)
+}
+
+/// Creates a new string of random numeric characters for a set length using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_numeric_string_of_length()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_numeric_string_of_length_with(&mut rng, 50);
+/// ```
+pub fn some_numeric_string_of_length_with<R: Rng + ?Sized>(rng: &mut R, to_bound: usize) -> String {
+    some_numeric_string_of_length_between_with(rng, to_bound, to_bound)
 }
 
 /// Creates a new string of random numeric characters for a length between two values
@@ -216,8 +431,31 @@ pub fn some_numeric_string_of_length(to_bound: usize) -> String {
 /// let s = some_numeric_string_of_length_between(50, 100);
 /// ```
 pub fn some_numeric_string_of_length_between(from_bound: usize, to_bound: usize) -> String {
-    let bound = some_number_between(from_bound, to_bound);
-    string_for_charset(bound, NUMERIC_CHARSET)
+    some_numeric_string_of_length_between_with(&mut seeded_rng(), from_bound, to_bound)
+}
+
+/// Creates a new string of random numeric characters for a length between two values using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_numeric_string_of_length_between()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_numeric_string_of_length_between_with(&mut rng, 50, 100);
+/// ```
+pub fn some_numeric_string_of_length_between_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    from_bound: usize,
+    to_bound: usize,
+) -> String {
+    let bound = some_number_between_with(rng, from_bound, to_bound);
+    string_for_charset(rng, bound, NUMERIC_CHARSET)
 }
 
 /// Creates a new string of random alpha characters
@@ -238,7 +476,26 @@ pub fn some_numeric_string_of_length_between(from_bound: usize, to_bound: usize)
 /// let s = some_numeric_string();
 /// ```
 pub fn some_alpha_string() -> String {
-    some_alpha_string_of_length_between(DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+    some_alpha_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random alpha characters using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alpha_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alpha_string_with(&mut rng);
+/// ```
+pub fn some_alpha_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    some_alpha_string_of_length_between_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
 }
 
 /// Creates a new string of random alpha characters for a set length
@@ -259,7 +516,26 @@ pub fn some_alpha_string() -> String {
 /// let s = some_alpha_string_of_length(50);
 /// ```
 pub fn some_alpha_string_of_length(to_bound: usize) -> String {
-    some_alpha_string_of_length_between(to_bound, to_bound)
+    some_alpha_string_of_length_with(&mut seeded_rng(), to_bound)
+}
+
+/// Creates a new string of random alpha characters for a set length using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alpha_string_of_length()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alpha_string_of_length_with(&mut rng, 50);
+/// ```
+pub fn some_alpha_string_of_length_with<R: Rng + ?Sized>(rng: &mut R, to_bound: usize) -> String {
+    some_alpha_string_of_length_between_with(rng, to_bound, to_bound)
 }
 
 /// Creates a new string of random alpha characters for a length between two values
@@ -280,24 +556,439 @@ pub fn some_alpha_string_of_length(to_bound: usize) -> String {
 /// let s = some_alpha_string_of_length_between(50, 100);
 /// ```
 pub fn some_alpha_string_of_length_between(from_bound: usize, to_bound: usize) -> String {
-    let bound = some_number_between(from_bound, to_bound);
-    string_for_charset(bound, ALPHA_CHARSET)
+    some_alpha_string_of_length_between_with(&mut seeded_rng(), from_bound, to_bound)
 }
 
-fn string_for_charset(bound: usize, charset: &[u8]) -> String {
-    (0..bound)
-        .map(|_| charset[some_number_less_than(charset.len())] as char)
-        .collect()
+/// Creates a new string of random alpha characters for a length between two values using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_alpha_string_of_length_between()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_alpha_string_of_length_between_with(&mut rng, 50, 100);
+/// ```
+pub fn some_alpha_string_of_length_between_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    from_bound: usize,
+    to_bound: usize,
+) -> String {
+    let bound = some_number_between_with(rng, from_bound, to_bound);
+    string_for_charset(rng, bound, ALPHA_CHARSET)
+}
+
+/// Creates a new string of random characters from a charset for a length within a range
+///
+/// Creates a new string of characters drawn from `charset`, with a number of characters within
+/// `length`, which accepts any range such as `5..10`, `5..=10`, `..10` or `5..`.
+/// A range without an end is limited to 1024 characters.
+///
+/// # Panics
+///
+/// Panics when `length` is empty, or when `charset` is empty and the length is not `0`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let hex = Charset::from('0'..='9').union('a'..='f');
+/// let s = some_string_from(&hex, 32..=32);
+/// ```
+pub fn some_string_from<B: RangeBounds<usize>>(charset: &Charset, length: B) -> String {
+    some_string_from_with(&mut seeded_rng(), charset, length)
+}
+
+/// Creates a new string of random characters from a charset for a length within a range using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string_from()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let hex = Charset::from('0'..='9').union('a'..='f');
+/// let s = some_string_from_with(&mut rng, &hex, 8..16);
+/// ```
+pub fn some_string_from_with<R, B>(rng: &mut R, charset: &Charset, length: B) -> String
+where
+    R: Rng + ?Sized,
+    B: RangeBounds<usize>,
+{
+    let bound = try_some_length_in_with(rng, length, DEFAULT_MAX_LENGTH)
+        .unwrap_or_else(|_| panic!("Cannot generate a string with a length in an empty range"));
+    if bound > 0 && charset.is_empty() {
+        panic!("Cannot generate a string from an empty charset")
+    }
+    charset.sample_string(rng, bound)
+}
+
+/// Creates a new string of random Unicode characters
+///
+/// Creates a new string of a random length between 1 and 1024 characters.
+/// Each character is drawn from `Charset::unicode()`, which includes ASCII, accented Latin letters,
+/// CJK characters, emoji, combining marks and right-to-left letters.
+/// The length is counted in characters, the number of bytes is usually larger.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let s = some_unicode_string();
+/// ```
+pub fn some_unicode_string() -> String {
+    some_unicode_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random Unicode characters using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_unicode_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_unicode_string_with(&mut rng);
+/// ```
+pub fn some_unicode_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    some_unicode_string_of_length_between_with(rng, DEFAULT_MIN_LENGTH, DEFAULT_MAX_LENGTH)
+}
+
+/// Creates a new string of random Unicode characters for a set length
+///
+/// Creates a new string of `to_bound: usize` characters, not bytes.
+/// Each character is drawn from `Charset::unicode()`, which includes ASCII, accented Latin letters,
+/// CJK characters, emoji, combining marks and right-to-left letters.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let s = some_unicode_string_of_length(50);
+/// assert_eq!(s.chars().count(), 50);
+/// ```
+pub fn some_unicode_string_of_length(to_bound: usize) -> String {
+    some_unicode_string_of_length_with(&mut seeded_rng(), to_bound)
+}
+
+/// Creates a new string of random Unicode characters for a set length using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_unicode_string_of_length()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_unicode_string_of_length_with(&mut rng, 50);
+/// ```
+pub fn some_unicode_string_of_length_with<R: Rng + ?Sized>(rng: &mut R, to_bound: usize) -> String {
+    some_unicode_string_of_length_between_with(rng, to_bound, to_bound)
+}
+
+/// Creates a new string of random Unicode characters for a length between two values
+///
+/// Creates a new string of random characters between `from_bound: usize` and `to_bound: usize`
+/// characters, not bytes.
+/// Each character is drawn from `Charset::unicode()`, which includes ASCII, accented Latin letters,
+/// CJK characters, emoji, combining marks and right-to-left letters.
+/// To draw from specific Unicode blocks, use `some_string_from()` with a union of the `Charset`
+/// presets, such as `Charset::cjk().union(Charset::emoji())`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let s = some_unicode_string_of_length_between(50, 100);
+/// ```
+pub fn some_unicode_string_of_length_between(from_bound: usize, to_bound: usize) -> String {
+    some_unicode_string_of_length_between_with(&mut seeded_rng(), from_bound, to_bound)
+}
+
+/// Creates a new string of random Unicode characters for a length between two values using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_unicode_string_of_length_between()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_unicode_string_of_length_between_with(&mut rng, 50, 100);
+/// ```
+pub fn some_unicode_string_of_length_between_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    from_bound: usize,
+    to_bound: usize,
+) -> String {
+    let bound = some_number_between_with(rng, from_bound, to_bound);
+    let charset = Charset::unicode();
+    charset.sample_string(rng, bound)
+}
+
+/// Creates a new string matched by a regular expression
+///
+/// Parses `pattern` as a regular expression and creates a random string it matches.
+/// Classes such as `\d`, `\w` or `.` draw their characters from `Charset::unicode()` where
+/// possible, so `\d` generates `0-9`. Unbounded repetitions such as `*`, `+` or `{2,}` repeat at
+/// most 8 times more than their minimum.
+/// Anchors and word boundaries are ignored, so patterns relying on them to reject strings may
+/// generate strings they do not match.
+///
+/// # Panics
+///
+/// Panics when `pattern` is not a valid regular expression.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let sku = some_string_matching(r"[A-Z]{3}-\d{4}");
+/// assert_eq!(sku.len(), 8);
+/// ```
+pub fn some_string_matching(pattern: &str) -> String {
+    some_string_matching_with(&mut seeded_rng(), pattern)
+}
+
+/// Creates a new string matched by a regular expression using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string_matching()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let sku = some_string_matching_with(&mut rng, r"[A-Z]{3}-\d{4}");
+/// ```
+pub fn some_string_matching_with<R: Rng + ?Sized>(rng: &mut R, pattern: &str) -> String {
+    regex::string_matching(rng, pattern)
+}
+
+/// Creates a new string following a pattern of placeholders
+///
+/// Creates a new string by replacing every placeholder in `pattern` with a random character,
+/// all other characters are copied as they are.
+/// * `#` is replaced with a character of `some_numeric_string()`
+/// * `?` is replaced with a character of `some_alpha_string()`
+/// * `*` is replaced with a character of `some_alphanumeric_string()`
+/// * `\` copies the character following it, so `\#` produces a literal `#`
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let code = some_string_from_pattern("###-???-**");
+/// assert_eq!(code.len(), 10);
+/// ```
+pub fn some_string_from_pattern(pattern: &str) -> String {
+    some_string_from_pattern_with(&mut seeded_rng(), pattern)
+}
+
+/// Creates a new string following a pattern of placeholders using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_string_from_pattern()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let code = some_string_from_pattern_with(&mut rng, "###-???-**");
+/// ```
+pub fn some_string_from_pattern_with<R: Rng + ?Sized>(rng: &mut R, pattern: &str) -> String {
+    let mut chars = pattern.chars();
+    let mut string = String::with_capacity(pattern.len());
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => string.push(char_for_charset(rng, NUMERIC_CHARSET)),
+            '?' => string.push(char_for_charset(rng, ALPHA_CHARSET)),
+            '*' => string.push(char_for_charset(rng, ALPHANUMERIC_CHARSET)),
+            '\\' => string.push(chars.next().unwrap_or('\\')),
+            literal => string.push(literal),
+        }
+    }
+    string
+}
+
+/// Creates a new string known to break software
+///
+/// Creates a new string from a random `NaughtyCategory`, such as SQL and shell injection
+/// payloads, format specifiers, zero-width and bidirectional override characters, overlong
+/// strings, null bytes, reserved file names and emoji joined by zero-width joiners.
+/// Input validation should reject or safely handle every one of these strings.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let s = some_naughty_string();
+/// ```
+pub fn some_naughty_string() -> String {
+    some_naughty_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string known to break software using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_naughty_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_naughty_string_with(&mut rng);
+/// ```
+pub fn some_naughty_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let categories = NaughtyCategory::ALL;
+    let category = categories[some_number_less_than_with(rng, categories.len())];
+    some_naughty_string_of_with(rng, category)
+}
+
+/// Creates a new string known to break software from a category
+///
+/// Creates a new string from the given `NaughtyCategory`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let s = some_naughty_string_of(NaughtyCategory::SqlInjection);
+/// ```
+pub fn some_naughty_string_of(category: NaughtyCategory) -> String {
+    some_naughty_string_of_with(&mut seeded_rng(), category)
+}
+
+/// Creates a new string known to break software from a category using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_naughty_string_of()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_naughty_string_of_with(&mut rng, NaughtyCategory::BidiOverride);
+/// ```
+pub fn some_naughty_string_of_with<R: Rng + ?Sized>(
+    rng: &mut R,
+    category: NaughtyCategory,
+) -> String {
+    naughty::naughty_string(rng, category)
+}
+
+/// Creates a new random character
+///
+/// Creates a new character from the same set as `some_string()`.
+/// Each character can be any ASCII value with a valid character value (Excluding values such as
+/// NUL, BELL, DEL) and does not include the extended ASCII character set.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+/// let c = some_char();
+/// ```
+pub fn some_char() -> char {
+    some_char_with(&mut seeded_rng())
+}
+
+/// Creates a new random character using `rng`
+///
+/// The characters are drawn from `rng` instead of the seeded random number generator of the current
+/// thread, otherwise see `some_char()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::string::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let c = some_char_with(&mut rng);
+/// ```
+pub fn some_char_with<R: Rng + ?Sized>(rng: &mut R) -> char {
+    char_for_charset(rng, ASCII_CHARSET)
+}
+
+/// Creates a string of `bound` characters of an ASCII charset
+///
+/// Samples every character from a single `Uniform` distribution and builds the string from its
+/// bytes, rather than setting up a new range for every character.
+fn string_for_charset<R: Rng + ?Sized>(rng: &mut R, bound: usize, charset: &[u8]) -> String {
+    let indexes = Uniform::from(0..charset.len());
+    let bytes = rng
+        .sample_iter(indexes)
+        .take(bound)
+        .map(|index| charset[index])
+        .collect();
+    String::from_utf8(bytes).expect("ASCII charsets are valid UTF-8")
+}
+
+fn char_for_charset<R: Rng + ?Sized>(rng: &mut R, charset: &[u8]) -> char {
+    charset[some_number_less_than_with(rng, charset.len())] as char
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::some_number_between;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn can_create_some_string() {
         let actual = some_string();
-        assert!(actual.len() > 0);
+        assert!(!actual.is_empty());
     }
 
     #[test]
@@ -319,7 +1010,7 @@ mod tests {
     #[test]
     fn can_create_some_alpha_string() {
         let actual = some_alpha_string();
-        assert!(actual.len() > 0);
+        assert!(!actual.is_empty());
     }
 
     #[test]
@@ -341,7 +1032,7 @@ mod tests {
     #[test]
     fn can_create_some_alphanumeric_string() {
         let actual = some_alphanumeric_string();
-        assert!(actual.len() > 0);
+        assert!(!actual.is_empty());
     }
 
     #[test]
@@ -363,7 +1054,7 @@ mod tests {
     #[test]
     fn can_create_some_numeric_string() {
         let actual = some_numeric_string();
-        assert!(actual.len() > 0);
+        assert!(!actual.is_empty());
     }
 
     #[test]
@@ -381,4 +1072,161 @@ mod tests {
         assert!(actual.len() >= min_length);
         assert!(actual.len() <= max_length);
     }
+
+    #[test]
+    fn can_create_some_char() {
+        let actual = some_char();
+        assert!(ASCII_CHARSET.contains(&(actual as u8)));
+    }
+
+    #[test]
+    fn can_create_some_string_from_charset() {
+        let charset = Charset::from('0'..='9').union('a'..='f');
+        let actual = some_string_from(&charset, 10..20);
+        assert!(actual.len() >= 10);
+        assert!(actual.len() < 20);
+        assert!(actual.chars().all(|c| charset.contains(c)));
+    }
+
+    #[test]
+    fn can_create_some_string_from_charset_for_open_range() {
+        let charset = Charset::from('a'..='z');
+        assert!(some_string_from(&charset, ..5).len() < 5);
+        assert!(some_string_from(&charset, 5..).len() >= 5);
+        assert!(some_string_from(&charset, ..).len() <= DEFAULT_MAX_LENGTH);
+    }
+
+    #[test]
+    fn can_create_empty_string_from_empty_charset() {
+        assert_eq!(some_string_from(&Charset::new(), 0..=0), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "empty charset")]
+    fn cannot_create_some_string_from_empty_charset() {
+        some_string_from(&Charset::new(), 1..10);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn cannot_create_some_string_from_charset_for_empty_range() {
+        some_string_from(&Charset::alpha(), 10..10);
+    }
+
+    #[test]
+    fn can_create_some_unicode_string() {
+        let actual = some_unicode_string();
+        assert!(!actual.is_empty());
+    }
+
+    #[test]
+    fn can_create_some_unicode_string_of_length() {
+        let length = some_number_between(1, 64);
+        let actual = some_unicode_string_of_length(length);
+        assert_eq!(actual.chars().count(), length);
+    }
+
+    #[test]
+    fn can_create_some_unicode_string_of_length_between() {
+        let min_length = some_number_between(1, 32);
+        let max_length = some_number_between(33, 64);
+        let actual = some_unicode_string_of_length_between(min_length, max_length);
+        assert!(actual.chars().count() >= min_length);
+        assert!(actual.chars().count() <= max_length);
+    }
+
+    #[test]
+    fn can_create_some_unicode_string_with_multibyte_chars() {
+        let actual = some_unicode_string_of_length(1024);
+        assert!(actual.len() > actual.chars().count());
+    }
+
+    #[test]
+    fn can_create_some_string_matching_regex() {
+        let patterns = [
+            r"[A-Z]{3}-\d{4}",
+            r"(foo|bar)+baz?",
+            r"[^\W_]{1,16}@example\.(com|org)",
+            r"\p{Greek}+ \w* .{3}",
+            r"(?i)ticket-[a-f0-9]{8}",
+        ];
+        for pattern in patterns {
+            let regex = ::regex::Regex::new(&format!("^(?:{pattern})$")).unwrap();
+            for _ in 0..100 {
+                let actual = some_string_matching(pattern);
+                assert!(
+                    regex.is_match(&actual),
+                    "{actual:?} does not match {pattern}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_create_some_string_matching_digits_from_ascii() {
+        let actual = some_string_matching(r"\d{64}");
+        assert!(actual.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex")]
+    fn cannot_create_some_string_matching_invalid_regex() {
+        some_string_matching(r"[A-Z");
+    }
+
+    #[test]
+    fn can_create_some_string_from_pattern() {
+        let actual = some_string_from_pattern("###-???-**");
+        let chars: Vec<char> = actual.chars().collect();
+        assert_eq!(chars.len(), 10);
+        assert!(chars[0..3]
+            .iter()
+            .all(|&c| NUMERIC_CHARSET.contains(&(c as u8))));
+        assert_eq!(chars[3], '-');
+        assert!(chars[4..7]
+            .iter()
+            .all(|&c| ALPHA_CHARSET.contains(&(c as u8))));
+        assert_eq!(chars[7], '-');
+        assert!(chars[8..]
+            .iter()
+            .all(|&c| ALPHANUMERIC_CHARSET.contains(&(c as u8))));
+    }
+
+    #[test]
+    fn can_create_some_string_from_pattern_with_escaped_placeholders() {
+        assert_eq!(some_string_from_pattern(r"\#\?\*\\"), r"#?*\");
+        assert_eq!(
+            some_string_from_pattern("no placeholders"),
+            "no placeholders"
+        );
+    }
+
+    #[test]
+    fn can_create_some_naughty_string() {
+        let _ = some_naughty_string();
+    }
+
+    #[test]
+    fn can_create_some_naughty_string_of_category() {
+        let actual = some_naughty_string_of(NaughtyCategory::NullByte);
+        assert!(actual.contains('\0'));
+    }
+
+    #[test]
+    fn can_create_same_strings_from_same_rng() {
+        let mut first = StdRng::seed_from_u64(42);
+        let mut second = StdRng::seed_from_u64(42);
+        assert_eq!(some_string_with(&mut first), some_string_with(&mut second));
+        assert_eq!(
+            some_alphanumeric_string_of_length_between_with(&mut first, 10, 20),
+            some_alphanumeric_string_of_length_between_with(&mut second, 10, 20)
+        );
+    }
+
+    #[test]
+    fn can_create_iterator_of_strings() {
+        let actual: Vec<String> = some_strings().take(10).collect();
+        assert_eq!(actual.len(), 10);
+        assert!(actual.iter().all(|s| !s.is_empty()));
+    }
 }
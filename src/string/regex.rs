@@ -0,0 +1,98 @@
+use super::Charset;
+use crate::number::some_number_between_inclusive_with;
+use rand::Rng;
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+/// The number of extra repetitions generated for `*`, `+` and `{n,}`
+const DEFAULT_MAX_REPETITIONS: u32 = 8;
+
+/// A regex reduced to what is needed to generate the strings it matches
+enum Node {
+    Literal(String),
+    Class(Charset),
+    Repetition { min: u32, max: u32, node: Box<Node> },
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+}
+
+/// Parses `pattern` and generates a string matched by it
+pub(super) fn string_matching<R: Rng + ?Sized>(rng: &mut R, pattern: &str) -> String {
+    let hir = regex_syntax::parse(pattern).unwrap_or_else(|error| {
+        panic!("Cannot generate a string matching an invalid regex:\n{error}")
+    });
+    let mut string = String::new();
+    Node::from(&hir).generate(rng, &mut string);
+    string
+}
+
+impl Node {
+    fn generate<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String) {
+        match self {
+            Node::Literal(literal) => string.push_str(literal),
+            Node::Class(charset) => string.push(charset.sample(rng)),
+            Node::Repetition { min, max, node } => {
+                for _ in 0..some_number_between_inclusive_with(rng, *min, *max) {
+                    node.generate(rng, string);
+                }
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    node.generate(rng, string);
+                }
+            }
+            Node::Alternation(nodes) => nodes[rng.gen_range(0..nodes.len())].generate(rng, string),
+        }
+    }
+}
+
+impl From<&Hir> for Node {
+    fn from(hir: &Hir) -> Self {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => Node::Literal(String::new()),
+            HirKind::Literal(literal) => {
+                Node::Literal(String::from_utf8_lossy(&literal.0).into_owned())
+            }
+            HirKind::Class(class) => {
+                let charset = class_charset(class);
+                if charset.is_empty() {
+                    panic!("Cannot generate a string matching a regex with an empty class")
+                }
+                Node::Class(charset)
+            }
+            HirKind::Repetition(repetition) => Node::Repetition {
+                min: repetition.min,
+                max: repetition
+                    .max
+                    .unwrap_or_else(|| repetition.min.saturating_add(DEFAULT_MAX_REPETITIONS)),
+                node: Box::new(Node::from(repetition.sub.as_ref())),
+            },
+            HirKind::Capture(capture) => Node::from(capture.sub.as_ref()),
+            HirKind::Concat(hirs) => Node::Concat(hirs.iter().map(Node::from).collect()),
+            HirKind::Alternation(hirs) => Node::Alternation(hirs.iter().map(Node::from).collect()),
+        }
+    }
+}
+
+/// Converts a regex class to a charset, preferring the characters of `Charset::unicode()`
+///
+/// Classes such as `\d`, `\w` or `.` match characters of many scripts, limiting them to the
+/// Unicode charset keeps `\d` to `0-9` while still matching any class outside of it.
+fn class_charset(class: &Class) -> Charset {
+    let charset: Charset = match class {
+        Class::Unicode(class) => class
+            .iter()
+            .map(|range| range.start()..=range.end())
+            .collect(),
+        Class::Bytes(class) => class
+            .iter()
+            .filter(|range| range.start().is_ascii())
+            .map(|range| range.start() as char..=range.end().min(0x7F) as char)
+            .collect(),
+    };
+    let preferred = charset.clone().intersect(Charset::unicode());
+    if preferred.is_empty() {
+        charset
+    } else {
+        preferred
+    }
+}
@@ -0,0 +1,238 @@
+use crate::error::{Error, Result};
+use crate::generator::Generator;
+use crate::rng::seeded_rng;
+use crate::string::some_string_with;
+use rand::Rng;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// The number of values drawn for a single unique value before giving up
+const MAX_ATTEMPTS: usize = 1000;
+
+thread_local! {
+    static UNIQUE_STRINGS: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// A generator never returning the same value twice
+///
+/// Remembers every value it returned and draws values from the wrapped generator until it finds
+/// one it did not return yet.
+/// The values are only remembered as long as the `Unique` lives, creating one per test keeps
+/// values unique within that test.
+/// Created with `unique()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// use rustyaid::unique::*;
+///
+/// let ids = unique(numbers(0..3));
+/// let mut values: Vec<u8> = vec![ids.generate(), ids.generate(), ids.generate()];
+/// values.sort();
+/// assert_eq!(values, vec![0, 1, 2]);
+/// assert!(ids.try_generate().is_err());
+/// ```
+pub struct Unique<T> {
+    generator: Generator<T>,
+    generated: RefCell<HashSet<T>>,
+}
+
+impl<T: Eq + Hash + Clone + 'static> Unique<T> {
+    /// Generates a new value that was not generated before
+    ///
+    /// Draws the value from the seeded random number generator of the current thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the wrapped generator keeps returning values that were already generated, see
+    /// `try_generate()`.
+    pub fn generate(&self) -> T {
+        self.generate_with(&mut seeded_rng())
+    }
+
+    /// Generates a new value that was not generated before using the provided random number
+    /// generator
+    pub fn generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.try_generate_with(rng)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Tries to generate a new value that was not generated before
+    ///
+    /// Returns `Error::Exhausted` when 1000 values in a row were already generated, usually because
+    /// the wrapped generator cannot return any new value.
+    pub fn try_generate(&self) -> Result<T> {
+        self.try_generate_with(&mut seeded_rng())
+    }
+
+    /// Tries to generate a new value that was not generated before using the provided random number
+    /// generator
+    pub fn try_generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T> {
+        for _ in 0..MAX_ATTEMPTS {
+            let value = self.generator.generate_with(rng);
+            if self.generated.borrow_mut().insert(value.clone()) {
+                return Ok(value);
+            }
+        }
+        Err(Error::Exhausted)
+    }
+
+    /// Forgets every value generated so far, allowing them to be generated again
+    pub fn clear(&self) {
+        self.generated.borrow_mut().clear()
+    }
+}
+
+/// Wraps a generator so it never returns the same value twice
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::generator::*;
+/// use rustyaid::string::Charset;
+/// use rustyaid::unique::*;
+///
+/// let codes = unique(strings_from(Charset::alpha(), 3..=3));
+/// let first = codes.generate();
+/// let second = codes.generate();
+/// assert_ne!(first, second);
+/// ```
+pub fn unique<T: Eq + Hash + Clone + 'static>(generator: Generator<T>) -> Unique<T> {
+    Unique {
+        generator,
+        generated: RefCell::new(HashSet::new()),
+    }
+}
+
+/// Creates a new string of random characters that was not returned before on the current thread
+///
+/// Works like `string::some_string()`, but remembers every string it returned on the current
+/// thread and never returns one of them again.
+/// The strings are remembered until `clear_unique_strings()` is called, test harnesses and async
+/// runtimes may run several tests on the same thread, so call it at the start of a test to keep
+/// the strings unique within that test only.
+///
+/// # Panics
+///
+/// Panics when no new string is found after 1000 attempts.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::unique::*;
+/// let first = some_unique_string();
+/// let second = some_unique_string();
+/// assert_ne!(first, second);
+/// ```
+pub fn some_unique_string() -> String {
+    some_unique_string_with(&mut seeded_rng())
+}
+
+/// Creates a new string of random characters that was not returned before on the current thread
+/// using the provided random number generator
+///
+/// Works like `some_unique_string()`, but draws the characters from `rng` instead of the seeded
+/// random number generator of the current thread.
+/// The strings are remembered in the same set as `some_unique_string()`.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rustyaid::unique::*;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let s = some_unique_string_with(&mut rng);
+/// ```
+pub fn some_unique_string_with<R: Rng + ?Sized>(rng: &mut R) -> String {
+    for _ in 0..MAX_ATTEMPTS {
+        let string = some_string_with(rng);
+        if UNIQUE_STRINGS.with(|strings| strings.borrow_mut().insert(string.clone())) {
+            return string;
+        }
+    }
+    panic!("{}", Error::Exhausted)
+}
+
+/// Forgets every string returned by `some_unique_string()` on the current thread
+///
+/// Allows the strings to be returned again and frees the memory used to remember them.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use rustyaid::unique::*;
+/// clear_unique_strings();
+/// let s = some_unique_string();
+/// ```
+pub fn clear_unique_strings() {
+    UNIQUE_STRINGS.with(|strings| strings.borrow_mut().clear())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{numbers, strings_from};
+    use crate::string::Charset;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn can_generate_every_unique_value() {
+        let generator = unique(numbers(0..100));
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut values: Vec<u32> = (0..100)
+            .map(|_| generator.generate_with(&mut rng))
+            .collect();
+        values.sort();
+        assert_eq!(values, (0..100).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn cannot_generate_unique_value_when_exhausted() {
+        let generator = unique(numbers(0..2));
+        generator.generate();
+        generator.generate();
+        assert_eq!(generator.try_generate(), Err(Error::Exhausted));
+        generator.clear();
+        assert!(generator.try_generate().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot generate a unique value")]
+    fn cannot_generate_unique_value_when_exhausted_without_try() {
+        let generator = unique(numbers(0..=0));
+        generator.generate();
+        generator.generate();
+    }
+
+    #[test]
+    fn can_generate_unique_short_strings() {
+        let generator = unique(strings_from(Charset::numeric(), 2..=2));
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: HashSet<String> = (0..100)
+            .map(|_| generator.generate_with(&mut rng))
+            .collect();
+        assert_eq!(values.len(), 100);
+    }
+
+    #[test]
+    fn can_create_some_unique_strings() {
+        let values: HashSet<String> = (0..1000).map(|_| some_unique_string()).collect();
+        assert_eq!(values.len(), 1000);
+    }
+
+    #[test]
+    fn can_clear_unique_strings() {
+        some_unique_string();
+        clear_unique_strings();
+        assert!(UNIQUE_STRINGS.with(|strings| strings.borrow().is_empty()));
+    }
+}